@@ -0,0 +1,1283 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use utilities::baseline;
+use utilities::input;
+use utilities::solve_log::DEFAULT_LOG_FILE;
+use utilities::submit::DEFAULT_HISTORY_FILE;
+use utilities::{BenchOptions, InputSource, OutputFormat};
+
+/// The year that's run when no `--year` is given.
+pub const DEFAULT_YEAR: usize = 2024;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code [OPTIONS]
+       advent_of_code log <import [NOTES] | render | check> [LOG OPTIONS]
+       advent_of_code compare <BASELINE> [BASELINE] [COMPARE OPTIONS]
+       advent_of_code new-day <DAY>
+       advent_of_code fetch --day <DAYS> [FETCH OPTIONS]
+       advent_of_code submit --day <DAY> --part <PART> [SUBMIT OPTIONS]
+       advent_of_code examples --day <DAY> --html <FILE> [--no-tests]
+       advent_of_code watch --day <DAY> [WATCH OPTIONS]
+
+Options:
+    --year <YEAR>     The year of the days to run (default 2024)
+    --all             Run every available day of the year (the default)
+    --day <DAYS>      Run a single day (7) or a range of days (5..12, 5..=12),
+                      can be given more than once
+    --part <PART>     Only run part 1 or part 2
+    --input <FILE>    Read the puzzle input from FILE, or from stdin if FILE is -,
+                      only valid when running a single day
+    --inputs-root <DIR>
+                      Read inputs from DIR/YEAR/dayN/input.txt, overriding
+                      the AOC_INPUTS environment variable
+    --bench           Run each step repeatedly and report min/median/mean/std dev
+                      timings, with parsing timed separately from the parts
+    --iterations <N>  Timed runs per step when benchmarking (default 20),
+                      implies --bench
+    --warmup <N>      Untimed runs per step before timing starts (default 3),
+                      implies --bench
+    --format <FORMAT> Print results as text (the default), json (one object per
+                      line) or csv, with a record for each day and part
+    --log <FILE>      Append the answers of solved parts to the solve log FILE
+    --verify          Check answers against the answers file, showing pass, fail
+                      or unknown for each part
+    --record          Like --verify, and also store answers that aren't known yet
+    --answers <FILE>  The answers file to verify against, by default
+                      YEAR/answers.txt in the inputs root
+    --save-baseline <NAME>
+                      Store the benchmark medians in baselines/NAME.txt (or in
+                      NAME if it's a path), implies --bench
+    --jobs <N>        Run days, and the parts of each day, on N threads, still
+                      printing the days in order, and report the wall time
+                      next to the sum of the task times
+    --all-inputs      Solve every input in YEAR/dayN/inputs in the inputs root,
+                      like alice.txt, and show a table of the answers and times,
+                      checked against the answers in alice.answers
+    --timeout <SECONDS>
+                      Give up on parsing or a part after SECONDS (fractions
+                      allowed), and report it as TIMEOUT; panics are always
+                      reported as PANIC. A timed-out part can't be stopped, so
+                      it slows down whatever runs after it until the run ends
+    -h, --help        Print this message
+
+When built with `--features alloc-stats`, each part also reports its peak heap
+use, and the bytes and number of allocations it made.
+
+Log commands:
+    log import [NOTES]
+                      Convert the hand-written notes (default notes.txt) into a
+                      new solve log, reporting entries that don't add up
+    log render        Print the solve log in the layout of the old notes
+    log check         Run the logged days and report answers that no longer match
+
+Log options:
+    --log <FILE>      The solve log (default solve_log.jsonl)
+    --year <YEAR>     The year the imported notes are for (default 2024), or
+                      the only year to render or check (default every year)
+    --inputs-root <DIR>
+                      Where log check reads inputs from
+
+Compare command:
+    compare <BASELINE> [BASELINE]
+                      Report the runtime change of each day and part between two
+                      baselines, or between a baseline and a fresh benchmark of
+                      the current build
+
+Compare options:
+    --threshold <PERCENT>
+                      Exit with an error if any part got slower by more than
+                      PERCENT (default 10)
+    --inputs-root <DIR>, --iterations <N>, --warmup <N>
+                      As above, for benchmarking the current build
+
+New day command:
+    new-day <DAY> [--year <YEAR>]
+                      Create src/yearYEAR/dayDAY.rs from src/template_day.rs
+                      and test_files/YEAR/dayDAY/example.txt, and register the
+                      day in src/lib.rs, refusing to overwrite an existing day
+
+Fetch command:
+    fetch --day <DAYS>
+                      Download the puzzle inputs of DAYS into the inputs root,
+                      skipping inputs that are already there
+
+Fetch options:
+    --year <YEAR>     The year of the puzzles (default 2024)
+    --inputs-root <DIR>
+                      Where inputs are saved (default AOC_INPUTS or test_files)
+    --session-file <FILE>
+                      Read the session cookie from FILE when AOC_SESSION isn't
+                      set (default .aoc_session)
+    --base-url <URL>  The server to download from, overriding AOC_BASE_URL
+
+Submit command:
+    submit --day <DAY> --part <PART>
+                      Compute the answer and submit it, unless the submission
+                      history shows it's wrong, the part is solved or the site
+                      is still making us wait after a wrong answer
+
+Submit options:
+    --year <YEAR>, --input <FILE>, --inputs-root <DIR>
+                      The year, and where the input is read from, as when
+                      running
+    --history <FILE>  The submission history (default submissions.jsonl)
+    --session-file <FILE>, --base-url <URL>
+                      As for fetch
+
+Examples command:
+    examples --day <DAY> --html <FILE>
+                      Save the examples in a saved puzzle page as
+                      test_files/YEAR/dayDAY/example.txt, example2.txt and so
+                      on, and add tests for the answers the page gives for them
+    --no-tests        Only save the examples
+    --year <YEAR>     The year of the puzzle (default 2024)
+
+Watch command:
+    watch --day <DAY> Rebuild and re-run the day on its examples and input
+                      whenever src/yearYEAR/dayDAY.rs, test_files/YEAR/dayDAY or
+                      the input changes, showing which answers changed since
+                      the last run
+
+Watch options:
+    --year <YEAR>, --inputs-root <DIR>
+                      The year, and where the input is read from, as when
+                      running
+    --interval <SECONDS>
+                      How often to check for changes (default 0.5)";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Args),
+    Log(LogCommand),
+    Compare(CompareArgs),
+    NewDay { year: usize, day: usize },
+    Fetch(FetchArgs),
+    Submit(SubmitArgs),
+    Examples(ExamplesArgs),
+    Watch(WatchArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExamplesArgs {
+    pub year: usize,
+    pub day: usize,
+    pub html: PathBuf,
+    /// Add tests for the guessed answers to the day's module.
+    pub tests: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WatchArgs {
+    pub year: usize,
+    pub day: usize,
+    pub root: PathBuf,
+    /// How long to wait between checks for changes.
+    pub interval: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SubmitArgs {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub input: InputSource,
+    pub history: PathBuf,
+    pub session_file: Option<PathBuf>,
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FetchArgs {
+    pub year: usize,
+    pub days: Vec<usize>,
+    pub root: PathBuf,
+    pub session_file: Option<PathBuf>,
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CompareArgs {
+    pub before: PathBuf,
+    /// Benchmark the current build when there's no second baseline.
+    pub after: Option<PathBuf>,
+    /// Slowdown in percent above which a part counts as a regression.
+    pub threshold: f64,
+    pub input: InputSource,
+    pub bench: BenchOptions,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LogCommand {
+    Import {
+        notes: PathBuf,
+        log: PathBuf,
+        year: usize,
+    },
+    Render {
+        log: PathBuf,
+        /// Only render this year, instead of every year in the log.
+        year: Option<usize>,
+    },
+    Check {
+        log: PathBuf,
+        input: InputSource,
+        /// Only check this year, instead of every year in the log.
+        year: Option<usize>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<usize>),
+}
+
+/// Checking answers against, and recording them in, an answers file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Verify {
+    pub answers: PathBuf,
+    pub record: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub year: usize,
+    pub days: DaySelection,
+    pub part: Option<usize>,
+    pub input: InputSource,
+    pub bench: Option<BenchOptions>,
+    pub format: OutputFormat,
+    pub log: Option<PathBuf>,
+    pub verify: Option<Verify>,
+    pub save_baseline: Option<PathBuf>,
+    /// Run on this many threads instead of one day after another.
+    pub jobs: Option<usize>,
+    /// How long a part may run before it's given up on.
+    pub timeout: Option<Duration>,
+    /// Run every named input of each day instead of its own input.
+    pub all_inputs: bool,
+    pub help: bool,
+}
+
+pub fn parse_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+
+    if args.next_if(|arg| arg == "log").is_some() {
+        return parse_log_command(args);
+    }
+
+    if args.next_if(|arg| arg == "compare").is_some() {
+        return parse_compare_command(args);
+    }
+
+    if args.next_if(|arg| arg == "new-day").is_some() {
+        return parse_new_day_command(args);
+    }
+
+    if args.next_if(|arg| arg == "fetch").is_some() {
+        return parse_fetch_command(args);
+    }
+
+    if args.next_if(|arg| arg == "submit").is_some() {
+        return parse_submit_command(args);
+    }
+
+    if args.next_if(|arg| arg == "examples").is_some() {
+        return parse_examples_command(args);
+    }
+
+    if args.next_if(|arg| arg == "watch").is_some() {
+        return parse_watch_command(args);
+    }
+
+    let args = parse_args(args)?;
+
+    Ok(if args.help {
+        Command::Help
+    } else {
+        Command::Run(args)
+    })
+}
+
+fn parse_log_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut action = None;
+    let mut notes = None;
+    let mut log = None;
+    let mut input = None;
+    let mut year = None;
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--log" => log = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--inputs-root" => input = Some(InputSource::Root(next_value(&mut args, &arg)?.into())),
+            "--year" => year = Some(parse_year(&next_value(&mut args, &arg)?)?),
+            "-h" | "--help" => return Ok(Command::Help),
+            "import" | "render" | "check" if action.is_none() => action = Some(arg),
+            _ if action.as_deref() == Some("import")
+                && notes.is_none()
+                && !arg.starts_with('-') =>
+            {
+                notes = Some(PathBuf::from(arg))
+            }
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    let log = log.unwrap_or_else(|| PathBuf::from(DEFAULT_LOG_FILE));
+
+    if input.is_some() && action.as_deref() != Some("check") {
+        return Err("--inputs-root can only be used with log check".to_string());
+    }
+
+    let command = match action.as_deref() {
+        Some("import") => LogCommand::Import {
+            notes: notes.unwrap_or_else(|| PathBuf::from("notes.txt")),
+            log,
+            year: year.unwrap_or(DEFAULT_YEAR),
+        },
+        Some("render") => LogCommand::Render { log, year },
+        Some("check") => LogCommand::Check {
+            log,
+            input: input.unwrap_or_default(),
+            year,
+        },
+        _ => return Err("Missing log command, expected import, render or check".to_string()),
+    };
+
+    Ok(Command::Log(command))
+}
+
+fn parse_compare_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut baselines = vec![];
+    let mut threshold = 10.0;
+    let mut input = None;
+    let mut bench = BenchOptions::default();
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threshold" => {
+                let value = next_value(&mut args, &arg)?;
+
+                threshold = value
+                    .trim_end_matches('%')
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|threshold| *threshold >= 0.0)
+                    .ok_or(format!("Invalid threshold '{value}'"))?;
+            }
+            "--inputs-root" => input = Some(InputSource::Root(next_value(&mut args, &arg)?.into())),
+            "--iterations" => {
+                bench.iterations = parse_count(&arg, &next_value(&mut args, &arg)?)?.max(1)
+            }
+            "--warmup" => bench.warmup = parse_count(&arg, &next_value(&mut args, &arg)?)?,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if !arg.starts_with('-') && baselines.len() < 2 => {
+                baselines.push(baseline::baseline_path(&arg))
+            }
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    let mut baselines = baselines.into_iter();
+    let before = baselines
+        .next()
+        .ok_or("Missing the baseline to compare against")?;
+    let after = baselines.next();
+
+    if after.is_some() && input.is_some() {
+        return Err("--inputs-root can't be used when comparing two baselines".to_string());
+    }
+
+    Ok(Command::Compare(CompareArgs {
+        before,
+        after,
+        threshold,
+        input: input.unwrap_or_default(),
+        bench,
+    }))
+}
+
+fn parse_new_day_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut day = None;
+    let mut year = DEFAULT_YEAR;
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&next_value(&mut args, &arg)?)?,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if day.is_none() && !arg.starts_with('-') => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    let day = day.ok_or("Missing the day to create")?;
+
+    Ok(Command::NewDay { year, day })
+}
+
+fn parse_fetch_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut year = DEFAULT_YEAR;
+    let mut days = vec![];
+    let mut root = None;
+    let mut session_file = None;
+    let mut base_url = None;
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&next_value(&mut args, &arg)?)?,
+            "--day" => days.extend(parse_days(&next_value(&mut args, &arg)?)?),
+            "--inputs-root" => root = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--session-file" => session_file = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--base-url" => base_url = Some(next_value(&mut args, &arg)?),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    if days.is_empty() {
+        return Err("Missing --day, the days to fetch".to_string());
+    }
+
+    Ok(Command::Fetch(FetchArgs {
+        year,
+        days,
+        root: root.unwrap_or_else(input::inputs_root),
+        session_file,
+        base_url,
+    }))
+}
+
+fn parse_submit_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut year = DEFAULT_YEAR;
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut history = None;
+    let mut session_file = None;
+    let mut base_url = None;
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&next_value(&mut args, &arg)?)?,
+            "--day" => day = Some(parse_day(&next_value(&mut args, &arg)?)?),
+            "--part" => {
+                let value = next_value(&mut args, &arg)?;
+
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Invalid part '{value}', expected 1 or 2")),
+                };
+            }
+            "--input" | "--inputs-root" if input.is_some() => {
+                return Err("--input and --inputs-root can only be given once".to_string());
+            }
+            "--input" => {
+                input = Some(match next_value(&mut args, &arg)?.as_str() {
+                    "-" => InputSource::Stdin,
+                    value => InputSource::File(PathBuf::from(value)),
+                })
+            }
+            "--inputs-root" => input = Some(InputSource::Root(next_value(&mut args, &arg)?.into())),
+            "--history" => history = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--session-file" => session_file = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--base-url" => base_url = Some(next_value(&mut args, &arg)?),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(Command::Submit(SubmitArgs {
+        year,
+        day: day.ok_or("Missing --day, the day to submit")?,
+        part: part.ok_or("Missing --part, the part to submit")?,
+        input: input.unwrap_or_default(),
+        history: history.unwrap_or_else(|| PathBuf::from(DEFAULT_HISTORY_FILE)),
+        session_file,
+        base_url,
+    }))
+}
+
+fn parse_examples_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut year = DEFAULT_YEAR;
+    let mut day = None;
+    let mut html = None;
+    let mut tests = true;
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&next_value(&mut args, &arg)?)?,
+            "--day" => day = Some(parse_day(&next_value(&mut args, &arg)?)?),
+            "--html" => html = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--no-tests" => tests = false,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(Command::Examples(ExamplesArgs {
+        year,
+        day: day.ok_or("Missing --day, the day the page is for")?,
+        html: html.ok_or("Missing --html, the saved puzzle page")?,
+        tests,
+    }))
+}
+
+fn parse_watch_command<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut year = DEFAULT_YEAR;
+    let mut day = None;
+    let mut root = None;
+    let mut interval = Duration::from_millis(500);
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&next_value(&mut args, &arg)?)?,
+            "--day" => day = Some(parse_day(&next_value(&mut args, &arg)?)?),
+            "--inputs-root" => root = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--interval" => interval = parse_seconds(&arg, &next_value(&mut args, &arg)?)?,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(Command::Watch(WatchArgs {
+        year,
+        day: day.ok_or("Missing --day, the day to watch")?,
+        root: root.unwrap_or_else(input::inputs_root),
+        interval,
+    }))
+}
+
+pub fn parse_args<I>(args: I) -> Result<Args, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut year = DEFAULT_YEAR;
+    let mut all = false;
+    let mut days = vec![];
+    let mut part = None;
+    let mut input = None;
+    let mut bench: Option<BenchOptions> = None;
+    let mut format = OutputFormat::default();
+    let mut log = None;
+    let mut verify = false;
+    let mut record = false;
+    let mut answers = None;
+    let mut save_baseline = None;
+    let mut jobs = None;
+    let mut timeout = None;
+    let mut all_inputs = false;
+    let mut help = false;
+
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&next_value(&mut args, &arg)?)?,
+            "--all" => all = true,
+            "--day" => {
+                let value = next_value(&mut args, &arg)?;
+                days.extend(parse_days(&value)?);
+            }
+            "--part" => {
+                let value = next_value(&mut args, &arg)?;
+
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Invalid part '{value}', expected 1 or 2")),
+                };
+            }
+            "--input" | "--inputs-root" => {
+                if input.is_some() {
+                    return Err("--input and --inputs-root can only be given once".to_string());
+                }
+
+                let value = next_value(&mut args, &arg)?;
+
+                input = Some(match (arg.as_str(), value.as_str()) {
+                    ("--input", "-") => InputSource::Stdin,
+                    ("--input", _) => InputSource::File(PathBuf::from(value)),
+                    _ => InputSource::Root(PathBuf::from(value)),
+                });
+            }
+            "--bench" => {
+                bench.get_or_insert_with(BenchOptions::default);
+            }
+            "--iterations" => {
+                let value = next_value(&mut args, &arg)?;
+                let iterations = parse_count(&arg, &value)?;
+
+                if iterations == 0 {
+                    return Err("--iterations must be at least 1".to_string());
+                }
+
+                bench.get_or_insert_with(BenchOptions::default).iterations = iterations;
+            }
+            "--warmup" => {
+                let value = next_value(&mut args, &arg)?;
+                bench.get_or_insert_with(BenchOptions::default).warmup = parse_count(&arg, &value)?;
+            }
+            "--format" => format = next_value(&mut args, &arg)?.parse()?,
+            "--log" => log = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--verify" => verify = true,
+            "--record" => record = true,
+            "--answers" => answers = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--save-baseline" => {
+                save_baseline = Some(baseline::baseline_path(&next_value(&mut args, &arg)?));
+                bench.get_or_insert_with(BenchOptions::default);
+            }
+            "--jobs" => {
+                let value = next_value(&mut args, &arg)?;
+
+                jobs = match parse_count(&arg, &value)? {
+                    0 => return Err("--jobs must be at least 1".to_string()),
+                    count => Some(count),
+                };
+            }
+            "--timeout" => {
+                let value = next_value(&mut args, &arg)?;
+                timeout = Some(parse_seconds(&arg, &value)?);
+            }
+            "--all-inputs" => all_inputs = true,
+            "-h" | "--help" => help = true,
+            _ => return Err(format!("Unknown argument '{arg}'")),
+        }
+    }
+
+    if jobs.is_some() && bench.is_some() {
+        return Err(
+            "--jobs can't be combined with benchmarking, the timings would disturb each other"
+                .to_string(),
+        );
+    }
+
+    if all_inputs {
+        let single_input = input.as_ref().is_some_and(InputSource::is_single_use);
+        let conflict = [
+            ("--input", single_input),
+            ("--verify and --record", verify || record),
+            ("--log", log.is_some()),
+            ("--save-baseline", save_baseline.is_some()),
+            ("--jobs", jobs.is_some()),
+        ]
+        .into_iter()
+        .find(|(_, given)| *given);
+
+        if let Some((flag, _)) = conflict {
+            return Err(format!("--all-inputs can't be combined with {flag}"));
+        }
+    }
+
+    let days = match (all, days.is_empty()) {
+        (true, false) => return Err("--all can't be combined with --day".to_string()),
+        (_, true) => DaySelection::All,
+        (false, false) => DaySelection::Days(days),
+    };
+
+    let input = input.unwrap_or_default();
+
+    if input.is_single_use() && !matches!(&days, DaySelection::Days(days) if days.len() == 1) {
+        return Err("--input can only be used when running a single --day".to_string());
+    }
+
+    if answers.is_some() && !(verify || record) {
+        return Err("--answers needs --verify or --record".to_string());
+    }
+
+    let verify = if verify || record {
+        let answers = answers
+            .or_else(|| input.answers_path(year))
+            .ok_or("--verify and --record need --answers when reading from --input")?;
+
+        Some(Verify { answers, record })
+    } else {
+        None
+    };
+
+    Ok(Args {
+        year,
+        days,
+        part,
+        input,
+        bench,
+        format,
+        log,
+        verify,
+        save_baseline,
+        jobs,
+        timeout,
+        all_inputs,
+        help,
+    })
+}
+
+fn next_value<I>(args: &mut I, flag: &str) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    args.next().ok_or(format!("Missing value for {flag}"))
+}
+
+fn parse_count(flag: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("Invalid value '{value}' for {flag}"))
+}
+
+fn parse_seconds(flag: &str, value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| *seconds > 0.0)
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or(format!(
+            "Invalid value '{value}' for {flag}, expected a positive number of seconds"
+        ))
+}
+
+fn parse_year(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(year @ 2015..=9999) => Ok(year),
+        _ => Err(format!("Invalid year '{value}', the first one was 2015")),
+    }
+}
+
+fn parse_day(value: &str) -> Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day '{value}', expected 1 to 25")),
+    }
+}
+
+fn parse_days(value: &str) -> Result<Vec<usize>, String> {
+    let days = if let Some((start, end)) = value.split_once("..=") {
+        (parse_day(start)?..=parse_day(end)?).collect::<Vec<_>>()
+    } else if let Some((start, end)) = value.split_once("..") {
+        // The end is one past the last day, so `20..26` runs through day 25.
+        let end = match end.trim().parse::<usize>() {
+            Ok(end @ 2..=26) => end,
+            _ => return Err(format!("Invalid day range end '{end}', expected 2 to 26")),
+        };
+        (parse_day(start)?..end).collect::<Vec<_>>()
+    } else {
+        vec![parse_day(value)?]
+    };
+
+    if days.is_empty() {
+        return Err(format!("Day range '{value}' is empty"));
+    }
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_default_is_all() {
+        let args = parse(&[]).unwrap();
+
+        assert_eq!(args.year, DEFAULT_YEAR);
+        assert_eq!(args.days, DaySelection::All);
+        assert_eq!(args.part, None);
+    }
+
+    #[test]
+    fn test_year() {
+        let args = parse(&["--year", "2023", "--day", "7"]).unwrap();
+
+        assert_eq!(args.year, 2023);
+        assert_eq!(args.days, DaySelection::Days(vec![7]));
+
+        let args = parse(&["--year", "2023", "--inputs-root", "inputs", "--verify"]).unwrap();
+        assert_eq!(
+            args.verify.unwrap().answers,
+            PathBuf::from("inputs").join("2023").join("answers.txt")
+        );
+
+        assert!(parse(&["--year", "2014"]).is_err());
+        assert!(parse(&["--year", "23"]).is_err());
+        assert!(parse(&["--year"]).is_err());
+    }
+
+    #[test]
+    fn test_single_day_and_part() {
+        let args = parse(&["--day", "7", "--part", "2"]).unwrap();
+
+        assert_eq!(args.days, DaySelection::Days(vec![7]));
+        assert_eq!(args.part, Some(2));
+    }
+
+    #[test]
+    fn test_day_ranges() {
+        let args = parse(&["--day", "5..8", "--day", "10..=11"]).unwrap();
+
+        assert_eq!(args.days, DaySelection::Days(vec![5, 6, 7, 10, 11]));
+
+        let args = parse(&["--day", "23..26"]).unwrap();
+        assert_eq!(args.days, DaySelection::Days(vec![23, 24, 25]));
+    }
+
+    #[test]
+    fn test_input_sources() {
+        let args = parse(&["--day", "3", "--input", "-"]).unwrap();
+        assert_eq!(args.input, InputSource::Stdin);
+
+        let args = parse(&["--day", "3", "--input", "my_input.txt"]).unwrap();
+        assert_eq!(args.input, InputSource::File(PathBuf::from("my_input.txt")));
+
+        let args = parse(&["--inputs-root", "../inputs"]).unwrap();
+        assert_eq!(args.input, InputSource::Root(PathBuf::from("../inputs")));
+
+        assert!(parse(&["--input", "-"]).is_err());
+        assert!(parse(&["--day", "1..3", "--input", "my_input.txt"]).is_err());
+        assert!(parse(&["--day", "3", "--input", "-", "--inputs-root", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_bench_options() {
+        assert_eq!(parse(&[]).unwrap().bench, None);
+        assert_eq!(
+            parse(&["--bench"]).unwrap().bench,
+            Some(BenchOptions::default())
+        );
+
+        let args = parse(&["--bench", "--iterations", "50", "--warmup", "0"]).unwrap();
+        assert_eq!(
+            args.bench,
+            Some(BenchOptions {
+                warmup: 0,
+                iterations: 50
+            })
+        );
+
+        let args = parse(&["--iterations", "10"]).unwrap();
+        assert_eq!(args.bench.unwrap().iterations, 10);
+
+        assert!(parse(&["--iterations", "0"]).is_err());
+        assert!(parse(&["--warmup", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(parse(&[]).unwrap().jobs, None);
+        assert_eq!(parse(&["--jobs", "4"]).unwrap().jobs, Some(4));
+
+        assert!(parse(&["--jobs", "0"]).is_err());
+        assert!(parse(&["--jobs"]).is_err());
+        assert!(parse(&["--jobs", "4", "--bench"]).is_err());
+        assert!(parse(&["--jobs", "4", "--save-baseline", "main"]).is_err());
+    }
+
+    #[test]
+    fn test_all_inputs() {
+        assert!(!parse(&[]).unwrap().all_inputs);
+
+        let args = parse(&["--all-inputs", "--inputs-root", "team", "--bench"]).unwrap();
+        assert!(args.all_inputs);
+        assert_eq!(args.input, InputSource::Root(PathBuf::from("team")));
+
+        assert!(parse(&["--all-inputs", "--day", "1", "--input", "a.txt"]).is_err());
+        assert!(parse(&["--all-inputs", "--verify"]).is_err());
+        assert!(parse(&["--all-inputs", "--jobs", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        assert_eq!(parse(&[]).unwrap().timeout, None);
+        assert_eq!(
+            parse(&["--timeout", "2.5"]).unwrap().timeout,
+            Some(Duration::from_millis(2500))
+        );
+
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "-1"]).is_err());
+        assert!(parse(&["--timeout", "soon"]).is_err());
+    }
+
+    #[test]
+    fn test_output_format() {
+        assert_eq!(parse(&[]).unwrap().format, OutputFormat::Text);
+        assert_eq!(
+            parse(&["--format", "json"]).unwrap().format,
+            OutputFormat::Json
+        );
+        assert!(parse(&["--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_verify_options() {
+        assert_eq!(parse(&[]).unwrap().verify, None);
+
+        let args = parse(&["--inputs-root", "inputs", "--verify"]).unwrap();
+        assert_eq!(
+            args.verify,
+            Some(Verify {
+                answers: PathBuf::from("inputs").join("2024").join("answers.txt"),
+                record: false,
+            })
+        );
+
+        let args = parse(&[
+            "--day",
+            "1",
+            "--input",
+            "-",
+            "--record",
+            "--answers",
+            "a.txt",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.verify,
+            Some(Verify {
+                answers: PathBuf::from("a.txt"),
+                record: true,
+            })
+        );
+
+        assert!(parse(&["--day", "1", "--input", "-", "--verify"]).is_err());
+        assert!(parse(&["--answers", "a.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_log_commands() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&["log", "import"]),
+            Ok(Command::Log(LogCommand::Import {
+                notes: PathBuf::from("notes.txt"),
+                log: PathBuf::from(DEFAULT_LOG_FILE),
+                year: DEFAULT_YEAR,
+            }))
+        );
+        assert_eq!(
+            command(&[
+                "log",
+                "import",
+                "old.txt",
+                "--log",
+                "new.jsonl",
+                "--year",
+                "2023"
+            ]),
+            Ok(Command::Log(LogCommand::Import {
+                notes: PathBuf::from("old.txt"),
+                log: PathBuf::from("new.jsonl"),
+                year: 2023,
+            }))
+        );
+        assert_eq!(
+            command(&["log", "check", "--inputs-root", "inputs"]),
+            Ok(Command::Log(LogCommand::Check {
+                log: PathBuf::from(DEFAULT_LOG_FILE),
+                input: InputSource::Root(PathBuf::from("inputs")),
+                year: None,
+            }))
+        );
+        assert!(matches!(
+            command(&["log", "check", "--year", "2023"]),
+            Ok(Command::Log(LogCommand::Check {
+                year: Some(2023),
+                ..
+            }))
+        ));
+        assert_eq!(
+            command(&["log", "render", "--year", "2023"]),
+            Ok(Command::Log(LogCommand::Render {
+                log: PathBuf::from(DEFAULT_LOG_FILE),
+                year: Some(2023),
+            }))
+        );
+        assert_eq!(command(&["log", "render", "-h"]), Ok(Command::Help));
+        assert_eq!(command(&["--help"]), Ok(Command::Help));
+        assert!(matches!(command(&["--day", "1"]), Ok(Command::Run(_))));
+
+        assert!(command(&["log"]).is_err());
+        assert!(command(&["log", "render", "extra.txt"]).is_err());
+        assert!(command(&["log", "render", "--inputs-root", "inputs"]).is_err());
+    }
+
+    #[test]
+    fn test_compare_command() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&["compare", "main", "--threshold", "5%", "--iterations", "50"]),
+            Ok(Command::Compare(CompareArgs {
+                before: PathBuf::from("baselines").join("main.txt"),
+                after: None,
+                threshold: 5.0,
+                input: InputSource::default(),
+                bench: BenchOptions {
+                    warmup: 3,
+                    iterations: 50,
+                },
+            }))
+        );
+
+        let Ok(Command::Compare(args)) = command(&["compare", "old", "new/run.txt"]) else {
+            panic!("expected a compare command");
+        };
+        assert_eq!(args.after, Some(PathBuf::from("new/run.txt")));
+        assert_eq!(args.threshold, 10.0);
+
+        assert!(command(&["compare"]).is_err());
+        assert!(command(&["compare", "a", "b", "c"]).is_err());
+        assert!(command(&["compare", "a", "--threshold", "-5"]).is_err());
+        assert!(command(&["compare", "a", "b", "--inputs-root", "x"]).is_err());
+        assert!(command(&["compare", "a", "--year", "2023"]).is_err());
+    }
+
+    #[test]
+    fn test_new_day_command() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&["new-day", "25"]),
+            Ok(Command::NewDay {
+                year: DEFAULT_YEAR,
+                day: 25
+            })
+        );
+        assert_eq!(
+            command(&["new-day", "--year", "2023", "1"]),
+            Ok(Command::NewDay { year: 2023, day: 1 })
+        );
+        assert_eq!(command(&["new-day", "--help"]), Ok(Command::Help));
+
+        assert!(command(&["new-day"]).is_err());
+        assert!(command(&["new-day", "0"]).is_err());
+        assert!(command(&["new-day", "26"]).is_err());
+        assert!(command(&["new-day", "24", "25"]).is_err());
+        assert!(command(&["new-day", "1", "--year", "2000"]).is_err());
+    }
+
+    #[test]
+    fn test_fetch_command() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&[
+                "fetch",
+                "--day",
+                "1..=3",
+                "--inputs-root",
+                "inputs",
+                "--base-url",
+                "http://localhost:8000"
+            ]),
+            Ok(Command::Fetch(FetchArgs {
+                year: DEFAULT_YEAR,
+                days: vec![1, 2, 3],
+                root: PathBuf::from("inputs"),
+                session_file: None,
+                base_url: Some("http://localhost:8000".to_string()),
+            }))
+        );
+
+        let Ok(Command::Fetch(args)) = command(&[
+            "fetch",
+            "--day",
+            "4",
+            "--session-file",
+            "cookie",
+            "--year",
+            "2023",
+        ]) else {
+            panic!("expected a fetch command");
+        };
+        assert_eq!(args.year, 2023);
+        assert_eq!(args.root, input::inputs_root());
+        assert_eq!(args.session_file, Some(PathBuf::from("cookie")));
+
+        assert!(command(&["fetch"]).is_err());
+        assert!(command(&["fetch", "--day", "26"]).is_err());
+        assert!(command(&["fetch", "--day", "1", "--part", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_submit_command() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&["submit", "--day", "3", "--part", "2", "--input", "in.txt"]),
+            Ok(Command::Submit(SubmitArgs {
+                year: DEFAULT_YEAR,
+                day: 3,
+                part: 2,
+                input: InputSource::File(PathBuf::from("in.txt")),
+                history: PathBuf::from(DEFAULT_HISTORY_FILE),
+                session_file: None,
+                base_url: None,
+            }))
+        );
+
+        let Ok(Command::Submit(args)) = command(&[
+            "submit",
+            "--part",
+            "1",
+            "--day",
+            "7",
+            "--history",
+            "old.jsonl",
+            "--base-url",
+            "http://localhost:8000",
+        ]) else {
+            panic!("expected a submit command");
+        };
+        assert_eq!(args.input, InputSource::default());
+        assert_eq!(args.history, PathBuf::from("old.jsonl"));
+        assert_eq!(args.base_url.as_deref(), Some("http://localhost:8000"));
+
+        assert!(command(&["submit", "--day", "3"]).is_err());
+        assert!(command(&["submit", "--part", "1"]).is_err());
+        assert!(command(&["submit", "--day", "3..5", "--part", "1"]).is_err());
+        assert!(command(&["submit", "--day", "3", "--part", "3"]).is_err());
+        assert!(command(&[
+            "submit",
+            "--day",
+            "3",
+            "--part",
+            "1",
+            "--input",
+            "a",
+            "--inputs-root",
+            "b"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_examples_command() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&["examples", "--day", "12", "--html", "day12.html"]),
+            Ok(Command::Examples(ExamplesArgs {
+                year: DEFAULT_YEAR,
+                day: 12,
+                html: PathBuf::from("day12.html"),
+                tests: true,
+            }))
+        );
+        assert_eq!(
+            command(&[
+                "examples",
+                "--html",
+                "a.html",
+                "--no-tests",
+                "--day",
+                "1",
+                "--year",
+                "2023"
+            ]),
+            Ok(Command::Examples(ExamplesArgs {
+                year: 2023,
+                day: 1,
+                html: PathBuf::from("a.html"),
+                tests: false,
+            }))
+        );
+
+        assert!(command(&["examples", "--day", "12"]).is_err());
+        assert!(command(&["examples", "--html", "a.html"]).is_err());
+        assert!(command(&["examples", "--day", "0", "--html", "a.html"]).is_err());
+    }
+
+    #[test]
+    fn test_watch_command() {
+        let command = |args: &[&str]| parse_command(args.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            command(&["watch", "--day", "6", "--inputs-root", "in"]),
+            Ok(Command::Watch(WatchArgs {
+                year: DEFAULT_YEAR,
+                day: 6,
+                root: PathBuf::from("in"),
+                interval: Duration::from_millis(500),
+            }))
+        );
+
+        let Ok(Command::Watch(args)) = command(&["watch", "--interval", "2", "--day", "6"]) else {
+            panic!("expected a watch command");
+        };
+        assert_eq!(args.interval, Duration::from_secs(2));
+
+        assert!(command(&["watch"]).is_err());
+        assert!(command(&["watch", "--day", "26"]).is_err());
+        assert!(command(&["watch", "--day", "6", "--interval", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_save_baseline() {
+        let args = parse(&["--save-baseline", "main"]).unwrap();
+
+        assert_eq!(
+            args.save_baseline,
+            Some(PathBuf::from("baselines").join("main.txt"))
+        );
+        assert_eq!(args.bench, Some(BenchOptions::default()));
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(parse(&["--day", "x"]).is_err());
+        assert!(parse(&["--day", "8..5"]).is_err());
+        assert!(parse(&["--day", "0..=3"]).is_err());
+        assert!(parse(&["--day", "26"]).is_err());
+        assert!(parse(&["--day", "20..27"]).is_err());
+        assert!(parse(&["--day", "3..3"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--all", "--day", "1"]).is_err());
+        assert!(parse(&["--day"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use advent_of_code::REGISTRY;
+use utilities::baseline::{Baseline, Comparison};
+use utilities::RunOptions;
+
+use crate::cli::CompareArgs;
+
+pub fn run(args: CompareArgs) -> ExitCode {
+    let before = match load(&args.before) {
+        Ok(baseline) if baseline.is_empty() => {
+            eprintln!(
+                "{} has no timings to compare against",
+                args.before.display()
+            );
+
+            return ExitCode::FAILURE;
+        }
+        Ok(baseline) => baseline,
+        Err(message) => {
+            eprintln!("{message}");
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let after = match &args.after {
+        Some(path) => match load(path) {
+            Ok(baseline) => baseline,
+            Err(message) => {
+                eprintln!("{message}");
+
+                return ExitCode::FAILURE;
+            }
+        },
+        None => bench_current(&before, &args),
+    };
+
+    let comparisons = before.compare(&after);
+
+    println!(
+        "{:>4} {:>4} {:>4} {:>14} {:>14} {:>9}",
+        "Year", "Day", "Part", "Before", "After", "Change"
+    );
+
+    for comparison in &comparisons {
+        println!("{}", format_comparison(comparison, args.threshold));
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(args.threshold))
+        .count();
+
+    if regressions == 0 {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!(
+            "{regressions} part(s) got slower by more than {}%",
+            args.threshold
+        );
+
+        ExitCode::FAILURE
+    }
+}
+
+fn load(path: &Path) -> Result<Baseline, String> {
+    Baseline::load(path).map_err(|error| format!("Couldn't read {}: {error}", path.display()))
+}
+
+/// Benchmarks the days in `before` with the current build, each under the year it's from.
+fn bench_current(before: &Baseline, args: &CompareArgs) -> Baseline {
+    let options = RunOptions {
+        part: None,
+        input: args.input.clone(),
+        bench: Some(args.bench),
+        timeout: None,
+    };
+
+    let mut after = Baseline::default();
+
+    for (year, day) in before.days() {
+        let Some(solution) = REGISTRY.get(year, day) else {
+            continue;
+        };
+
+        let report = solution.run(&options);
+
+        if let Some(error) = &report.error {
+            eprintln!("{error}");
+        }
+
+        after.add_report(&report);
+    }
+
+    after
+}
+
+fn format_comparison(comparison: &Comparison, threshold: f64) -> String {
+    let after = comparison
+        .after
+        .map_or("missing".to_string(), |after| format!("{after:.2?}"));
+    let change = comparison
+        .change()
+        .map_or(String::new(), |change| format!("{change:+.1}%"));
+    let flag = if comparison.is_regression(threshold) {
+        "  regression"
+    } else {
+        ""
+    };
+
+    format!(
+        "{:>4} {:>4} {:>4} {:>14} {:>14} {:>9}{flag}",
+        comparison.year,
+        comparison.day,
+        comparison.part,
+        format!("{:.2?}", comparison.before),
+        after,
+        change
+    )
+}
@@ -0,0 +1,275 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use utilities::examples::{self, Example};
+use utilities::input::{self, TEST_FILES_ROOT};
+
+use crate::cli::ExamplesArgs;
+
+pub fn run(args: ExamplesArgs) -> ExitCode {
+    match extract(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn extract(args: &ExamplesArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let html = read(&args.html)?;
+    let examples = examples::extract_examples(&html);
+
+    if examples.is_empty() {
+        return Err(format!("No examples found in {}", args.html.display()));
+    }
+
+    let dir = input::day_dir(Path::new(TEST_FILES_ROOT), year, day);
+    fs::create_dir_all(&dir)
+        .map_err(|error| format!("Couldn't create {}: {error}", dir.display()))?;
+
+    let mut stubs = vec![];
+
+    for (idx, example) in examples.iter().enumerate() {
+        let file_name = example_file_name(idx);
+        let path = dir.join(&file_name);
+
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == example.text => {
+                println!("{} is unchanged", path.display())
+            }
+            // Empty files are placeholders, like the one `new-day` creates.
+            Ok(existing) if !existing.trim().is_empty() => {
+                println!(
+                    "{} already exists with different contents, leaving it alone",
+                    path.display()
+                );
+                continue;
+            }
+            _ => {
+                fs::write(&path, &example.text)
+                    .map_err(|error| format!("Couldn't write {}: {error}", path.display()))?;
+                println!("Wrote {}", path.display());
+            }
+        }
+
+        stubs.extend(test_stubs(&file_name, example));
+    }
+
+    let module = Path::new("src")
+        .join(format!("year{year}"))
+        .join(format!("day{day}.rs"));
+
+    if !args.tests || stubs.is_empty() || !module.exists() {
+        return Ok(());
+    }
+
+    let (source, added) = add_test_stubs(&read(&module)?, day, &stubs);
+
+    for stub in stubs.iter().filter(|stub| !added.contains(&stub.name())) {
+        println!(
+            "{} is already in {}, the page gives {}",
+            stub.name(),
+            module.display(),
+            stub.answer
+        );
+    }
+
+    if added.is_empty() {
+        return Ok(());
+    }
+
+    fs::write(&module, source)
+        .map_err(|error| format!("Couldn't write {}: {error}", module.display()))?;
+
+    for name in added {
+        println!("Added {name} to {}", module.display());
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Couldn't read {}: {error}", path.display()))
+}
+
+/// `example.txt` for the first example, then `example2.txt`, `example3.txt` and so on.
+fn example_file_name(idx: usize) -> String {
+    match idx {
+        0 => "example.txt".to_string(),
+        idx => format!("example{}.txt", idx + 1),
+    }
+}
+
+/// A test to add for one part of an example.
+#[derive(Debug, PartialEq, Eq)]
+struct TestStub {
+    file_name: String,
+    part: usize,
+    answer: String,
+}
+
+impl TestStub {
+    fn name(&self) -> String {
+        let stem = self.file_name.trim_end_matches(".txt");
+
+        format!("test_{stem}_part_{}", self.part)
+    }
+
+    fn render(&self, day: usize) -> String {
+        let answer = if self.answer.parse::<i128>().is_ok() {
+            self.answer.clone()
+        } else {
+            format!("{:?}", self.answer)
+        };
+
+        format!(
+            "    #[test]
+    fn {}() {{
+        let contents = utilities::read_file_data(Day{day}::YEAR, Day{day}::DAY, \"{}\").unwrap();
+        let input = Day{day}::parse(&contents).unwrap();
+
+        assert_eq!(Day{day}::part_{}(&input).unwrap(), {answer});
+    }}
+",
+            self.name(),
+            self.file_name,
+            self.part
+        )
+    }
+}
+
+fn test_stubs(file_name: &str, example: &Example) -> Vec<TestStub> {
+    example
+        .answers
+        .iter()
+        .zip(1..)
+        .filter_map(|(answer, part)| {
+            Some(TestStub {
+                file_name: file_name.to_string(),
+                part,
+                answer: answer.clone()?,
+            })
+        })
+        .collect()
+}
+
+/// Adds the stubs that aren't in the day's tests yet, each in front of the input test of its
+/// part, the way the tests are ordered by hand. Returns the new source and the added tests.
+fn add_test_stubs(source: &str, day: usize, stubs: &[TestStub]) -> (String, Vec<String>) {
+    let mut source = source.to_string();
+    let mut added = vec![];
+
+    if !source.contains("mod tests {") {
+        source.push_str("\n#[cfg(test)]\nmod tests {\n    use super::*;\n}\n");
+    }
+
+    for stub in stubs {
+        let name = stub.name();
+
+        if source.contains(&format!("fn {name}(")) {
+            continue;
+        }
+
+        let input_test = format!("    #[test]\n    fn test_input_part_{}(", stub.part);
+        let rendered = stub.render(day);
+
+        match source.find(&input_test) {
+            Some(position) => source.insert_str(position, &format!("{rendered}\n")),
+            None => {
+                // The closing brace of `mod tests`, which is the last item.
+                let position = source.trim_end().len() - 1;
+                source.insert_str(position, &format!("\n{rendered}"));
+            }
+        }
+
+        added.push(name);
+    }
+
+    (source, added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+pub struct Day7;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day7::YEAR, Day7::DAY, \"example.txt\").unwrap();
+        let input = Day7::parse(&contents).unwrap();
+
+        assert_eq!(Day7::part_1(&input).unwrap(), 3749);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day7::YEAR, Day7::DAY, \"input.txt\").unwrap();
+        let input = Day7::parse(&contents).unwrap();
+
+        assert_eq!(Day7::part_1(&input).unwrap(), 1);
+    }
+}
+";
+
+    fn stub(file_name: &str, part: usize, answer: &str) -> TestStub {
+        TestStub {
+            file_name: file_name.to_string(),
+            part,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_test_stubs() {
+        let stubs = [
+            stub("example.txt", 1, "3749"),
+            stub("example2.txt", 1, "4,6,3"),
+            stub("example.txt", 2, "11387"),
+        ];
+
+        let (source, added) = add_test_stubs(SOURCE, 7, &stubs);
+
+        assert_eq!(added, ["test_example2_part_1", "test_example_part_2"]);
+        assert!(source.contains(
+            "assert_eq!(Day7::part_1(&input).unwrap(), \"4,6,3\");\n    }\n\n    #[test]\n    \
+             fn test_input_part_1() {"
+        ));
+        assert!(source
+            .ends_with("        assert_eq!(Day7::part_2(&input).unwrap(), 11387);\n    }\n}\n"));
+        assert!(source.contains("    }\n\n    #[test]\n    fn test_example_part_2() {"));
+
+        let (again, added) = add_test_stubs(&source, 7, &stubs);
+
+        assert!(added.is_empty());
+        assert_eq!(again, source);
+    }
+
+    #[test]
+    fn test_add_test_stubs_without_tests() {
+        let (source, added) =
+            add_test_stubs("pub struct Day7;\n", 7, &[stub("example.txt", 1, "3749")]);
+
+        assert_eq!(added, ["test_example_part_1"]);
+        assert!(source.starts_with(
+            "pub struct Day7;\n\n#[cfg(test)]\nmod tests {\n    use super::*;\n\n    #[test]\n    \
+             fn test_example_part_1() {"
+        ));
+        assert!(source.ends_with("3749);\n    }\n}\n"));
+    }
+
+    #[test]
+    fn test_example_file_name() {
+        assert_eq!(example_file_name(0), "example.txt");
+        assert_eq!(example_file_name(2), "example3.txt");
+    }
+}
@@ -0,0 +1,51 @@
+use std::process::ExitCode;
+
+use utilities::client::{self, Client, ClientError};
+use utilities::fetch::{self, FetchError, Fetched};
+
+use crate::cli::FetchArgs;
+
+pub fn run(args: FetchArgs) -> ExitCode {
+    let session = match client::read_session(args.session_file.as_deref()) {
+        Ok(session) => session,
+        Err(message) => {
+            eprintln!("{message}");
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let base_url = args.base_url.unwrap_or_else(client::base_url);
+    let mut client = Client::new(&base_url, &session);
+    let mut failed = false;
+
+    for day in args.days {
+        match fetch::fetch_input(&mut client, &args.root, args.year, day) {
+            Ok(Fetched::Downloaded(path)) => println!("Day {day}: saved {}", path.display()),
+            Ok(Fetched::Cached(path)) => {
+                println!(
+                    "Day {day}: {} already exists, not downloading it again",
+                    path.display()
+                )
+            }
+            Err(error) => {
+                eprintln!("Day {day}: {error}");
+                failed = true;
+
+                // Every other day would be rejected the same way.
+                if matches!(
+                    error,
+                    FetchError::Client(ClientError::Status { status: 400, .. })
+                ) {
+                    break;
+                }
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,127 @@
+//! Advent of Code solutions, by year and day.
+//!
+//! Every solved day is a [`Solution`] in a module like [`year2024::day6`], and is listed in
+//! [`REGISTRY`]. Use [`solve`] to get an answer from a puzzle input, or a day's type to call its
+//! parser and parts directly:
+//!
+//! ```
+//! use advent_of_code::year2024::day1::Day1;
+//! use advent_of_code::Solution;
+//!
+//! let input = Day1::parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+//!
+//! assert_eq!(Day1::part_1(&input).unwrap(), 11);
+//! assert_eq!(advent_of_code::solve(2024, 1, 2, "3   4\n4   3\n").unwrap(), "7");
+//! ```
+//!
+//! The grids, points, directions, neighbors and searches the days share live in
+//! [`utilities::grid`], [`utilities::point`], [`utilities::direction`], [`utilities::neighbors`]
+//! and [`utilities::search`], so every year can build on them.
+
+pub mod year2024;
+
+pub use utilities::{DynSolution, PuzzleError, PuzzleResult, Registry, Solution};
+
+/// Every solved day, in year and day order.
+pub const REGISTRY: Registry = Registry::new(&[
+    &year2024::day1::Day1,
+    &year2024::day2::Day2,
+    &year2024::day3::Day3,
+    &year2024::day4::Day4,
+    &year2024::day5::Day5,
+    &year2024::day6::Day6,
+    &year2024::day7::Day7,
+    &year2024::day8::Day8,
+    &year2024::day9::Day9,
+    &year2024::day10::Day10,
+    &year2024::day11::Day11,
+    &year2024::day12::Day12,
+    &year2024::day13::Day13,
+    &year2024::day14::Day14,
+    &year2024::day15::Day15,
+    &year2024::day16::Day16,
+    &year2024::day17::Day17,
+    &year2024::day18::Day18,
+    &year2024::day19::Day19,
+    &year2024::day20::Day20,
+    &year2024::day21::Day21,
+    &year2024::day22::Day22,
+    &year2024::day23::Day23,
+    &year2024::day24::Day24,
+]);
+
+/// The solution of `day` of `year`, if it's solved.
+pub fn solution(year: usize, day: usize) -> Option<&'static dyn DynSolution> {
+    REGISTRY.get(year, day)
+}
+
+/// Solves `part` of `day` of `year` from the puzzle input in `contents`, formatting the answer
+/// the way it's submitted.
+pub fn solve(year: usize, day: usize, part: usize, contents: &str) -> PuzzleResult<String> {
+    let solution = solution(year, day).ok_or_else(|| {
+        PuzzleError::solve(format!("there's no solution for day {day} of {year}"))
+    })?;
+
+    solution.solve(part, &solution.parse(contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utilities::answers::{Answers, Verdict};
+    use utilities::RunOptions;
+
+    #[test]
+    fn test_registry_is_in_day_order() {
+        let days = REGISTRY
+            .iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect::<Vec<_>>();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_solve() {
+        let contents = utilities::read_file_data(2024, 1, "example.txt").unwrap();
+
+        assert_eq!(solve(2024, 1, 1, &contents).unwrap(), "11");
+        assert_eq!(solve(2024, 1, 2, &contents).unwrap(), "31");
+        assert!(solve(2024, 1, 3, &contents).is_err());
+        assert!(solve(2015, 1, 1, &contents).is_err());
+        assert!(solve(2024, 1, 1, "not a list").is_err());
+    }
+
+    /// Checks every registered day that has an input against the answers on record. This runs
+    /// the slow days again on top of their own tests, so it's only run with `--ignored`.
+    #[test]
+    #[ignore]
+    fn test_registered_answers() {
+        let options = RunOptions::default();
+
+        for solution in REGISTRY.iter() {
+            let (year, day) = (solution.year(), solution.day());
+
+            if options.input.read(year, day).is_err() {
+                continue;
+            }
+
+            let answers_path = options.input.answers_path(year).unwrap();
+            let answers = Answers::load(&answers_path).unwrap();
+
+            let mut report = solution.run(&options);
+            report.verify(&answers);
+
+            for part in &report.parts {
+                assert!(
+                    !matches!(part.verdict, Some(Verdict::Fail { .. })),
+                    "{year} day {} part {}: {:?}, expected {:?}",
+                    report.day,
+                    part.part,
+                    part.answer,
+                    part.verdict
+                );
+            }
+        }
+    }
+}
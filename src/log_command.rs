@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use advent_of_code::REGISTRY;
+use utilities::notes;
+use utilities::solve_log::{self, LogEntry};
+use utilities::{InputSource, RunOptions};
+
+use crate::cli::LogCommand;
+
+pub fn run(command: LogCommand) -> ExitCode {
+    let result = match command {
+        LogCommand::Import { notes, log, year } => import(&notes, &log, year),
+        LogCommand::Render { log, year } => read_log(&log, year).map(|entries| {
+            print!("{}", solve_log::render(&entries));
+            true
+        }),
+        LogCommand::Check { log, input, year } => {
+            read_log(&log, year).map(|entries| check(&entries, input))
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{message}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the log, keeping only the entries for `year` when one is given.
+fn read_log(log: &Path, year: Option<usize>) -> Result<Vec<LogEntry>, String> {
+    let mut entries = solve_log::read_log(log)
+        .map_err(|error| format!("Couldn't read {}: {error}", log.display()))?;
+
+    if let Some(year) = year {
+        entries.retain(|entry| entry.year == year);
+    }
+
+    Ok(entries)
+}
+
+/// Converts the notes for `year` into a new log. Refuses to touch a log that already has
+/// entries, since importing twice would duplicate them.
+fn import(notes_path: &Path, log: &Path, year: usize) -> Result<bool, String> {
+    if !read_log(log, None)?.is_empty() {
+        return Err(format!("{} already has entries", log.display()));
+    }
+
+    let contents = fs::read_to_string(notes_path)
+        .map_err(|error| format!("Couldn't read {}: {error}", notes_path.display()))?;
+
+    let (entries, issues) = notes::parse_notes(&contents, year);
+
+    for issue in &issues {
+        eprintln!("{}:{issue}", notes_path.display());
+    }
+
+    solve_log::append_log(log, &entries)
+        .map_err(|error| format!("Couldn't write {}: {error}", log.display()))?;
+
+    println!(
+        "Imported {} entries into {} with {} issue(s)",
+        entries.len(),
+        log.display(),
+        issues.len()
+    );
+
+    Ok(true)
+}
+
+/// Runs every day with a logged answer, under the year it was logged for, and reports the
+/// answers the code no longer produces.
+fn check(entries: &[LogEntry], input: InputSource) -> bool {
+    let answers = solve_log::latest_answers(entries);
+
+    let mut days = answers
+        .iter()
+        .map(|(year, day, _, _)| (*year, *day))
+        .collect::<Vec<_>>();
+    days.dedup();
+
+    let mut matched = 0;
+
+    for (year, day) in days {
+        let logged = answers
+            .iter()
+            .filter(|(logged_year, logged_day, _, _)| (*logged_year, *logged_day) == (year, day))
+            .collect::<Vec<_>>();
+
+        let Some(solution) = REGISTRY.get(year, day) else {
+            println!("{year} day {day}: no solution to check against");
+            continue;
+        };
+
+        let options = RunOptions {
+            part: match logged[..] {
+                [(_, _, part, _)] => Some(*part),
+                _ => None,
+            },
+            input: input.clone(),
+            bench: None,
+            timeout: None,
+        };
+
+        let report = solution.run(&options);
+
+        for (_, _, part, recorded) in logged {
+            let produced = report
+                .parts
+                .iter()
+                .find(|report| report.part == *part)
+                .map(|report| &report.answer);
+
+            match produced {
+                Some(Ok(answer)) if answer == recorded => matched += 1,
+                Some(Ok(answer)) => println!(
+                    "{year} day {day} part {part}: logged {recorded}, but the code now produces {answer}"
+                ),
+                Some(Err(error)) => println!("{year} day {day} part {part}: couldn't check, {error}"),
+                None => println!("{year} day {day} part {part}: wasn't run"),
+            }
+        }
+    }
+
+    println!("{matched} of {} logged answers match", answers.len());
+
+    matched == answers.len()
+}
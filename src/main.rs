@@ -1,53 +1,417 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-fn main() {
-    println!("Advent of code 2024");
-
-    day1::run();
-    day2::run();
-    day3::run();
-    day4::run();
-    day5::run();
-    day6::run();
-    day7::run();
-    day8::run();
-    day9::run();
-    day10::run();
-    day11::run();
-    day12::run();
-    day13::run();
-    day14::run();
-    day15::run();
-    day16::run();
-    day17::run();
-    day18::run();
-    day19::run();
-    day20::run();
-    day21::run();
-    day22::run();
-    day23::run();
-    day24::run();
+mod cli;
+mod compare_command;
+mod examples_command;
+mod fetch_command;
+mod log_command;
+mod new_day_command;
+mod submit_command;
+mod watch_command;
+
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use advent_of_code::REGISTRY;
+use cli::{Args, Command, DaySelection};
+use utilities::answers::{Answers, Verdict};
+use utilities::baseline::Baseline;
+use utilities::input;
+use utilities::solve_log::{self, Timestamp};
+use utilities::{
+    named_inputs, parallel, solution, DayReport, DynSolution, InputSource, OutputFormat, RunOptions,
+};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: utilities::alloc::CountingAllocator = utilities::alloc::CountingAllocator;
+
+fn main() -> ExitCode {
+    let command = match cli::parse_command(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}\n\n{}", cli::USAGE);
+
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Run(args) => {
+            let exit_code = run_days(args);
+            warn_still_running();
+
+            exit_code
+        }
+        Command::Log(command) => log_command::run(command),
+        Command::Compare(args) => compare_command::run(args),
+        Command::NewDay { year, day } => new_day_command::run(year, day),
+        Command::Fetch(args) => fetch_command::run(args),
+        Command::Submit(args) => submit_command::run(args),
+        Command::Examples(args) => examples_command::run(args),
+        Command::Watch(args) => watch_command::run(args),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn run_days(args: Args) -> ExitCode {
+    let days = match select_days(args.year, &args.days) {
+        Ok(days) => days,
+        Err(message) => {
+            eprintln!("{message}");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let options = RunOptions {
+        part: args.part,
+        input: args.input,
+        bench: args.bench,
+        timeout: args.timeout,
+    };
+
+    if args.all_inputs {
+        return run_all_inputs(args.year, &days, args.format, &options);
+    }
+
+    let mut answers = match &args.verify {
+        Some(verify) => match Answers::load(&verify.answers) {
+            Ok(answers) => Some(answers),
+            Err(error) => {
+                eprintln!("Couldn't read {}: {error}", verify.answers.display());
+
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    if let Some(header) = args.format.header(args.year) {
+        println!("{header}");
+    }
+
+    let mut failed_days = vec![];
+    let mut verdicts = vec![];
+    let mut recorded = 0;
+    let mut baseline = Baseline::default();
+
+    let mut task_time = Duration::ZERO;
+    let start = Instant::now();
+
+    let mut handle_report = |mut report: DayReport| {
+        task_time += report.task_time();
+
+        if let (Some(verify), Some(answers)) = (&args.verify, &mut answers) {
+            report.verify(answers);
+            verdicts.extend(report.parts.iter().filter_map(|part| part.verdict.clone()));
+
+            if verify.record {
+                recorded += report.record(answers);
+            }
+        }
+
+        print!("{}", report.render(args.format));
+
+        if !report.is_ok() {
+            failed_days.push(report.day.to_string());
+        }
+
+        baseline.add_report(&report);
+
+        if let Some(log) = &args.log {
+            let entries = solve_log::solved_entries(&report, Timestamp::now());
+
+            if let Err(error) = solve_log::append_log(log, &entries) {
+                eprintln!("Couldn't write to {}: {error}", log.display());
+            }
+        }
+    };
+
+    match args.jobs {
+        Some(jobs) => parallel::run_parallel(&days, &options, jobs, &mut handle_report),
+        None => days
+            .iter()
+            .for_each(|solution| handle_report(solution.run(&options))),
+    }
+
+    if let Some(jobs) = args.jobs {
+        print_summary(
+            args.format,
+            &format!(
+                "Wall time: {:.2?}, task time: {task_time:.2?} on {jobs} thread(s)",
+                start.elapsed()
+            ),
+        );
+    }
+
+    let mut success = failed_days.is_empty();
+
+    if let Some(path) = &args.save_baseline {
+        success &= save_baseline(path, &baseline);
+    }
+
+    if let (Some(verify), Some(answers)) = (&args.verify, &answers) {
+        let count = |matches: fn(&Verdict) -> bool| verdicts.iter().filter(|v| matches(v)).count();
+        let failed = count(|verdict| matches!(verdict, Verdict::Fail { .. }));
+        let summary = format!(
+            "Verified: {} pass, {failed} fail, {} unknown",
+            count(|verdict| *verdict == Verdict::Pass),
+            count(|verdict| *verdict == Verdict::Unknown)
+        );
+
+        print_summary(args.format, &summary);
+
+        success &= failed == 0;
+
+        if recorded > 0 {
+            match answers.save(&verify.answers) {
+                Ok(()) => eprintln!(
+                    "Recorded {recorded} answer(s) in {}",
+                    verify.answers.display()
+                ),
+                Err(error) => {
+                    eprintln!("Couldn't write {}: {error}", verify.answers.display());
+                    success = false;
+                }
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        if !failed_days.is_empty() {
+            eprintln!("Failed days: {}", failed_days.join(", "));
+        }
+
+        ExitCode::FAILURE
+    }
+}
+
+/// Timed-out steps can't be stopped, and ran alongside everything after them. They end with the
+/// process, once `main` returns.
+fn warn_still_running() {
+    let still_running = solution::still_running();
+
+    if still_running > 0 {
+        eprintln!(
+            "{still_running} timed-out step(s) were still running, so the timings after the first \
+             timeout may be unreliable"
+        );
+    }
+}
+
+/// Solves every named input of the days, checking the answers against each input's answers
+/// file, and shows a table for each day.
+fn run_all_inputs(
+    year: usize,
+    days: &[&'static dyn DynSolution],
+    format: OutputFormat,
+    options: &RunOptions,
+) -> ExitCode {
+    let InputSource::Root(root) = &options.input else {
+        eprintln!("--all-inputs reads the inputs from an inputs root");
+
+        return ExitCode::from(2);
+    };
+
+    if let Some(header) = format.header(year) {
+        println!("{header}");
+    }
+
+    let mut success = true;
+    let mut verdicts = vec![];
+
+    for solution in days {
+        let day = solution.day();
+
+        let inputs = match named_inputs::named_inputs(root, year, day) {
+            Ok(inputs) => inputs,
+            Err(error) => {
+                eprintln!("Day {day}: couldn't list the inputs: {error}");
+                success = false;
+                continue;
+            }
+        };
+
+        if inputs.is_empty() {
+            if format == OutputFormat::Text {
+                let dir = input::day_dir(root, year, day).join(named_inputs::NAMED_INPUTS_DIR);
+
+                println!("[Day {day}]: no inputs in {}", dir.display());
+            }
+
+            continue;
+        }
+
+        let mut reports = vec![];
+
+        for input in inputs {
+            let options = RunOptions {
+                input: InputSource::File(input.path.clone()),
+                ..options.clone()
+            };
+            let mut report = solution.run(&options);
+            let answers_path = input.answers_path();
+
+            match Answers::load(&answers_path) {
+                Ok(answers) => report.verify(&answers),
+                Err(error) => eprintln!("Couldn't read {}: {error}", answers_path.display()),
+            }
+
+            success &= report.is_ok();
+            verdicts.extend(report.parts.iter().filter_map(|part| part.verdict.clone()));
+
+            if format != OutputFormat::Text {
+                print!("{}", report.render(format));
+            }
+
+            reports.push((input.name, report));
+        }
+
+        if format == OutputFormat::Text {
+            println!(
+                "[Day {day}]: {} input(s) in {:.2?}",
+                reports.len(),
+                named_inputs::total_time(&reports)
+            );
+            print!("{}", named_inputs::render_table(&reports));
+        }
+    }
+
+    let count = |matches: fn(&Verdict) -> bool| verdicts.iter().filter(|v| matches(v)).count();
+    let failed = count(|verdict| matches!(verdict, Verdict::Fail { .. }));
+
+    print_summary(
+        format,
+        &format!(
+            "Checked: {} pass, {failed} fail, {} unknown",
+            count(|verdict| *verdict == Verdict::Pass),
+            count(|verdict| *verdict == Verdict::Unknown)
+        ),
+    );
+
+    if success && failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Prints a line after the results, on stderr for the machine-readable formats so stdout stays
+/// parseable.
+fn print_summary(format: OutputFormat, summary: &str) {
+    if format == OutputFormat::Text {
+        println!("{summary}");
+    } else {
+        eprintln!("{summary}");
+    }
+}
+
+/// Merges the new timings into the baseline at `path`, keeping timings of days that weren't run.
+fn save_baseline(path: &Path, timings: &Baseline) -> bool {
+    let result = Baseline::load(path).and_then(|mut baseline| {
+        baseline.merge(timings);
+        baseline.save(path)
+    });
+
+    match result {
+        Ok(()) => {
+            eprintln!("Saved baseline {}", path.display());
+            true
+        }
+        Err(error) => {
+            eprintln!("Couldn't save baseline {}: {error}", path.display());
+            false
+        }
+    }
+}
+
+fn select_days(
+    year: usize,
+    selection: &DaySelection,
+) -> Result<Vec<&'static dyn DynSolution>, String> {
+    let DaySelection::Days(requested) = selection else {
+        let selected = REGISTRY.year(year).collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            return Err(no_days(year));
+        }
+
+        return Ok(selected);
+    };
+
+    let mut selected = vec![];
+    let mut unknown = vec![];
+
+    for day in requested {
+        match REGISTRY.get(year, *day) {
+            Some(solution) => selected.push(solution),
+            None => unknown.push(day.to_string()),
+        }
+    }
+
+    if !unknown.is_empty() {
+        let available = REGISTRY
+            .days(year)
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>();
+
+        if available.is_empty() {
+            return Err(no_days(year));
+        }
+
+        return Err(format!(
+            "Unknown day(s) of {year}: {}\nAvailable days: {}",
+            unknown.join(", "),
+            available.join(", ")
+        ));
+    }
+
+    Ok(selected)
+}
+
+fn no_days(year: usize) -> String {
+    let years = REGISTRY
+        .years()
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>();
+
+    format!(
+        "There are no days of {year}\nAvailable years: {}",
+        years.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_days() {
+        let selected = select_days(2024, &DaySelection::Days(vec![3, 1])).unwrap();
+        let days = selected
+            .iter()
+            .map(|solution| solution.day())
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, [3, 1]);
+        let Err(message) = select_days(2024, &DaySelection::Days(vec![1, 25])) else {
+            panic!("day 25 isn't solved yet");
+        };
+
+        assert!(message.contains("Unknown day(s) of 2024: 25"));
+
+        let Err(message) = select_days(2015, &DaySelection::All) else {
+            panic!("2015 has no days yet");
+        };
+
+        assert!(message.contains("Available years: 2024"));
+    }
 }
@@ -0,0 +1,354 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use utilities::input::{self, TEST_FILES_ROOT};
+
+const TEMPLATE: &str = "src/template_day.rs";
+const LIB: &str = "src/lib.rs";
+const TEMPLATE_DAY: &str = "Day0";
+const REGISTRY_START: &str = "pub const REGISTRY: Registry = Registry::new(&[";
+
+pub fn run(year: usize, day: usize) -> ExitCode {
+    match new_day(year, day) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+
+            println!("Registered day {day} of {year} in {LIB}");
+
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Creates the module and test files of a new day from the template, and registers it in its
+/// year's module and in `lib.rs`, creating the year's module for its first day. Every file is
+/// checked before anything is written, and if a write fails the ones before it are undone.
+fn new_day(year: usize, day: usize) -> Result<Vec<PathBuf>, String> {
+    let year_dir = PathBuf::from(format!("src/year{year}"));
+    let module = year_dir.join(format!("day{day}.rs"));
+    let year_module = year_dir.join("mod.rs");
+
+    let template = read(Path::new(TEMPLATE))?;
+    let source = fill_template(&template, day)?;
+    let lib = read(Path::new(LIB))?;
+    let registered_lib = register_day(&lib, year, day)?;
+
+    let year_change = match fs::read_to_string(&year_module) {
+        Ok(year_source) => Change {
+            contents: add_day_module(&year_source, day)?,
+            original: Some(year_source),
+            path: year_module,
+        },
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            Change::create(year_module, year_module_source(year, day))
+        }
+        Err(error) => return Err(format!("Couldn't read {}: {error}", year_module.display())),
+    };
+
+    let mut changes = vec![Change::create(module, source), year_change];
+
+    let example = input::day_dir(Path::new(TEST_FILES_ROOT), year, day).join("example.txt");
+    if !example.exists() {
+        changes.push(Change::create(example, String::new()));
+    }
+
+    changes.push(Change {
+        path: PathBuf::from(LIB),
+        contents: registered_lib,
+        original: Some(lib),
+    });
+
+    for change in &changes {
+        change.check()?;
+    }
+
+    let new_dirs = changes
+        .iter()
+        .filter_map(|change| change.path.parent())
+        .flat_map(|dir| dir.ancestors().take_while(|ancestor| !ancestor.exists()))
+        .map(Path::to_path_buf)
+        .collect::<Vec<_>>();
+
+    for (idx, change) in changes.iter().enumerate() {
+        if let Err(message) = change.apply() {
+            changes[..idx].iter().rev().for_each(Change::undo);
+
+            // Only the directories this run created, and only once they are empty again.
+            for dir in &new_dirs {
+                let _ = fs::remove_dir(dir);
+            }
+
+            return Err(message);
+        }
+    }
+
+    Ok(changes
+        .into_iter()
+        .filter(|change| change.original.is_none())
+        .map(|change| change.path)
+        .collect())
+}
+
+/// A file `new_day` writes, along with what it held before so a failed run can put it back.
+struct Change {
+    path: PathBuf,
+    contents: String,
+    original: Option<String>,
+}
+
+impl Change {
+    fn create(path: PathBuf, contents: String) -> Self {
+        Self {
+            path,
+            contents,
+            original: None,
+        }
+    }
+
+    /// Makes sure the file can be written: a new file mustn't exist yet, and an existing one
+    /// mustn't be read-only.
+    fn check(&self) -> Result<(), String> {
+        match &self.original {
+            None if self.path.exists() => Err(format!("{} already exists", self.path.display())),
+            Some(_) => match fs::metadata(&self.path) {
+                Ok(metadata) if metadata.permissions().readonly() => {
+                    Err(format!("{} is read-only", self.path.display()))
+                }
+                Ok(_) => Ok(()),
+                Err(error) => Err(format!("Couldn't read {}: {error}", self.path.display())),
+            },
+            None => Ok(()),
+        }
+    }
+
+    fn apply(&self) -> Result<(), String> {
+        let result = match (&self.original, self.path.parent()) {
+            (Some(_), _) => fs::write(&self.path, &self.contents),
+            (None, parent) => parent
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| {
+                    OpenOptions::new()
+                        .write(true)
+                        .create_new(true)
+                        .open(&self.path)
+                })
+                .and_then(|mut file| file.write_all(self.contents.as_bytes())),
+        };
+
+        result.map_err(|error| format!("Couldn't write {}: {error}", self.path.display()))
+    }
+
+    fn undo(&self) {
+        let result = match &self.original {
+            Some(original) => fs::write(&self.path, original),
+            None => fs::remove_file(&self.path),
+        };
+
+        if let Err(error) = result {
+            eprintln!(
+                "Couldn't undo the change to {}: {error}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Couldn't read {}: {error}", path.display()))
+}
+
+/// Turns the template into the source of `day`. The year comes from the year's module.
+fn fill_template(template: &str, day: usize) -> Result<String, String> {
+    const DAY_CONST: &str = "const DAY: usize = 0;";
+
+    if !template.contains(TEMPLATE_DAY) || !template.contains(DAY_CONST) {
+        return Err(format!(
+            "{TEMPLATE} should define {TEMPLATE_DAY} with '{DAY_CONST}'"
+        ));
+    }
+
+    Ok(template
+        .replace(TEMPLATE_DAY, &format!("Day{day}"))
+        .replace(DAY_CONST, &format!("const DAY: usize = {day};")))
+}
+
+/// The module of a year that only has `day` so far.
+fn year_module_source(year: usize, day: usize) -> String {
+    format!(
+        "pub mod day{day};\n\n/// The year of the puzzles in this module.\npub const YEAR: usize = {year};\n"
+    )
+}
+
+/// Adds `pub mod dayN;` among the other days of a year's module, keeping their order.
+fn add_day_module(source: &str, day: usize) -> Result<String, String> {
+    let module = format!("day{day}");
+    let mod_line = format!("pub mod {module};");
+
+    let mut lines = source.lines().collect::<Vec<_>>();
+
+    if lines.contains(&mod_line.as_str()) {
+        return Err(format!("Day {day} is already a module of its year"));
+    }
+
+    insert_sorted(&mut lines, "pub mod ", &module, &mod_line)?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` among the `{prefix}name;` lines, before the first name that sorts after
+/// `name`.
+fn insert_sorted<'a>(
+    lines: &mut Vec<&'a str>,
+    prefix: &str,
+    name: &str,
+    line: &'a str,
+) -> Result<(), String> {
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, line.strip_prefix(prefix)?.strip_suffix(';')?)))
+        .collect::<Vec<_>>();
+
+    let Some((last_mod, _)) = mods.last() else {
+        return Err("Couldn't find the module declarations".to_string());
+    };
+
+    let idx = mods
+        .iter()
+        .find(|(_, module)| *module > name)
+        .map_or(last_mod + 1, |(idx, _)| *idx);
+
+    lines.insert(idx, line);
+
+    Ok(())
+}
+
+/// Adds `&yearY::dayN::DayN` to the registry, keeping it in year and day order, along with
+/// `mod yearY;` among the other modules if this is the year's first day.
+fn register_day(lib: &str, year: usize, day: usize) -> Result<String, String> {
+    let year_module = format!("year{year}");
+    let mod_line = format!("pub mod {year_module};");
+    let entry = format!("    &{year_module}::day{day}::Day{day},");
+
+    let mut lines = lib.lines().collect::<Vec<_>>();
+
+    if lines.contains(&entry.as_str()) {
+        return Err(format!(
+            "Day {day} of {year} is already registered in {LIB}"
+        ));
+    }
+
+    if !lines.contains(&mod_line.as_str()) {
+        insert_sorted(&mut lines, "pub mod ", &year_module, &mod_line)
+            .map_err(|message| format!("{message} in {LIB}"))?;
+    }
+
+    let start = lines
+        .iter()
+        .position(|line| *line == REGISTRY_START)
+        .ok_or(format!("Couldn't find the registry in {LIB}"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "]);")
+            .ok_or(format!("Couldn't find the end of the registry in {LIB}"))?;
+
+    let registry_idx = (start + 1..end)
+        .find(|idx| registered_day(lines[*idx]).is_some_and(|registered| registered > (year, day)))
+        .unwrap_or(end);
+
+    lines.insert(registry_idx, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The year and day of a registry entry like `&year2024::day7::Day7,`.
+fn registered_day(line: &str) -> Option<(usize, usize)> {
+    let mut path = line.trim().strip_prefix("&year")?.split("::");
+    let year = path.next()?.parse().ok()?;
+    let day = path.next()?.strip_prefix("day")?.parse().ok()?;
+
+    Some((year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "\
+pub mod year2024;
+
+pub use utilities::{DynSolution, Registry};
+
+pub const REGISTRY: Registry = Registry::new(&[
+    &year2024::day1::Day1,
+    &year2024::day3::Day3,
+]);
+";
+
+    #[test]
+    fn test_register_day() {
+        assert_eq!(
+            register_day(LIB_RS, 2024, 2).unwrap(),
+            "\
+pub mod year2024;
+
+pub use utilities::{DynSolution, Registry};
+
+pub const REGISTRY: Registry = Registry::new(&[
+    &year2024::day1::Day1,
+    &year2024::day2::Day2,
+    &year2024::day3::Day3,
+]);
+"
+        );
+
+        let lib = register_day(LIB_RS, 2024, 25).unwrap();
+        assert!(lib.contains("&year2024::day3::Day3,\n    &year2024::day25::Day25,\n]);"));
+
+        let lib = register_day(LIB_RS, 2023, 25).unwrap();
+        assert!(lib.starts_with("pub mod year2023;\npub mod year2024;\n"));
+        assert!(lib.contains("[\n    &year2023::day25::Day25,\n    &year2024::day1::Day1,"));
+
+        assert!(register_day(LIB_RS, 2024, 3).is_err());
+        assert!(register_day("pub mod cli;\n", 2024, 2).is_err());
+    }
+
+    #[test]
+    fn test_year_modules() {
+        let source = year_module_source(2023, 7);
+
+        assert_eq!(
+            source,
+            "pub mod day7;\n\n/// The year of the puzzles in this module.\npub const YEAR: usize = 2023;\n"
+        );
+
+        let source = add_day_module(&source, 12).unwrap();
+        let source = add_day_module(&source, 1).unwrap();
+
+        assert!(source.starts_with("pub mod day1;\npub mod day12;\npub mod day7;\n\n"));
+        assert!(add_day_module(&source, 7).is_err());
+    }
+
+    #[test]
+    fn test_fill_template() {
+        let template = read(Path::new(TEMPLATE)).unwrap();
+        let source = fill_template(&template, 25).unwrap();
+
+        assert!(source.contains("pub struct Day25;"));
+        assert!(source.contains("const YEAR: usize = super::YEAR;"));
+        assert!(source.contains("const DAY: usize = 25;"));
+        assert!(source.contains("Day25::part_2(&input)"));
+        assert!(!source.contains("Day0"));
+        assert!(fill_template("", 25).is_err());
+    }
+}
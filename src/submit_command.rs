@@ -0,0 +1,99 @@
+use std::process::ExitCode;
+
+use advent_of_code::REGISTRY;
+use utilities::answers::Answers;
+use utilities::client::{self, Client};
+use utilities::submit::{self, Outcome, Submission};
+use utilities::RunOptions;
+
+use crate::cli::SubmitArgs;
+
+pub fn run(args: SubmitArgs) -> ExitCode {
+    match submit(&args) {
+        Ok(Outcome::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{message}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(args: &SubmitArgs) -> Result<Outcome, String> {
+    let (year, day, part) = (args.year, args.day, args.part);
+
+    let solution = REGISTRY
+        .get(year, day)
+        .ok_or(format!("There's no solution for day {day} of {year}"))?;
+
+    let history = submit::read_history(&args.history)
+        .map_err(|error| format!("Couldn't read {}: {error}", args.history.display()))?;
+
+    let options = RunOptions {
+        part: Some(part),
+        input: args.input.clone(),
+        bench: None,
+        timeout: None,
+    };
+
+    let report = solution.run(&options);
+    let answer = report
+        .parts
+        .iter()
+        .find(|report| report.part == part)
+        .map(|report| report.answer.clone())
+        .ok_or(format!("Day {day} part {part} wasn't run"))??;
+
+    println!("Day {day} part {part}: {answer}");
+
+    submit::check_submission(&history, year, day, part, &answer, submit::now())
+        .map_err(|refusal| format!("Not submitting {answer}, {refusal}"))?;
+
+    let session = client::read_session(args.session_file.as_deref())?;
+    let base_url = args.base_url.clone().unwrap_or_else(client::base_url);
+
+    let response = Client::new(&base_url, &session)
+        .submit(year, day, part, &answer)
+        .map_err(|error| format!("Couldn't submit day {day} part {part}: {error}"))?;
+
+    let submission = Submission {
+        year,
+        day,
+        part,
+        answer,
+        time: submit::now(),
+        outcome: submit::parse_response(&response),
+    };
+
+    println!("{}", submission.outcome);
+
+    submit::append_history(&args.history, &submission)
+        .map_err(|error| format!("Couldn't write {}: {error}", args.history.display()))?;
+
+    if submission.outcome == Outcome::Correct {
+        record_answer(args, &submission);
+    }
+
+    Ok(submission.outcome)
+}
+
+/// Adds a correct answer to the answers file of the inputs, so `--verify` knows it from now on.
+fn record_answer(args: &SubmitArgs, submission: &Submission) {
+    let Some(path) = args.input.answers_path(submission.year) else {
+        return;
+    };
+
+    let result = Answers::load(&path).and_then(|mut answers| {
+        if answers.get(submission.day, submission.part).is_some() {
+            return Ok(());
+        }
+
+        answers.insert(submission.day, submission.part, &submission.answer);
+        answers.save(&path)
+    });
+
+    if let Err(error) = result {
+        eprintln!("Couldn't record the answer in {}: {error}", path.display());
+    }
+}
@@ -1,15 +1,30 @@
-const DAY: usize = 0;
+use utilities::{PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(DAY, part_1, part_2);
-}
+type Input = Vec<String>;
 
-fn part_1(contents: &str) -> usize {
-    0
-}
+pub struct Day0;
+
+impl Solution for Day0 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 0;
 
-fn part_2(contents: &str) -> usize {
-    0
+    type Input = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Input> {
+        Ok(utilities::lines(contents)
+            .map(|line| line.text.to_string())
+            .collect())
+    }
+
+    fn part_1(_input: &Input) -> PuzzleResult<usize> {
+        Ok(0)
+    }
+
+    fn part_2(_input: &Input) -> PuzzleResult<usize> {
+        Ok(0)
+    }
 }
 
 #[cfg(test)]
@@ -18,29 +33,33 @@ mod tests {
 
     #[test]
     fn test_example_part_1() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day0::YEAR, Day0::DAY, "example.txt").unwrap();
+        let input = Day0::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 0);
+        assert_eq!(Day0::part_1(&input).unwrap(), 0);
     }
 
     #[test]
     fn test_input_part_1() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day0::YEAR, Day0::DAY, "input.txt").unwrap();
+        let input = Day0::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 0);
+        assert_eq!(Day0::part_1(&input).unwrap(), 0);
     }
 
     #[test]
     fn test_example_part_2() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day0::YEAR, Day0::DAY, "example.txt").unwrap();
+        let input = Day0::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 0);
+        assert_eq!(Day0::part_2(&input).unwrap(), 0);
     }
 
     #[test]
     fn test_input_part_2() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day0::YEAR, Day0::DAY, "input.txt").unwrap();
+        let input = Day0::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 0);
+        assert_eq!(Day0::part_2(&input).unwrap(), 0);
     }
 }
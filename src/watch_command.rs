@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+
+use utilities::input::{self, INPUT_FILE_NAME, TEST_FILES_ROOT};
+use utilities::watch::{self, RunAnswers, Snapshot};
+
+use crate::cli::WatchArgs;
+
+/// Parts that run for longer than this are reported as timed out, so a day stuck in an endless
+/// loop doesn't stop the watching.
+const TIMEOUT_SECONDS: &str = "10";
+
+pub fn run(args: WatchArgs) -> ExitCode {
+    let (year, day) = (args.year, args.day);
+    let source = Path::new("src")
+        .join(format!("year{year}"))
+        .join(format!("day{day}.rs"));
+    let examples = input::day_dir(Path::new(TEST_FILES_ROOT), year, day);
+    let input = input::day_dir(&args.root, year, day).join(INPUT_FILE_NAME);
+
+    if !source.exists() {
+        eprintln!(
+            "There's no {}, create the day with new-day first",
+            source.display()
+        );
+
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "Watching {}, {} and {}, press Ctrl-C to stop",
+        source.display(),
+        examples.display(),
+        input.display()
+    );
+
+    let watched = [source, examples.clone(), input.clone()];
+    let mut previous = Snapshot::new();
+    let mut answers = RunAnswers::new();
+
+    loop {
+        let snapshot = watch::snapshot(&watched);
+
+        if snapshot != previous {
+            println!("\n--- {} ---", describe_changes(&previous, &snapshot));
+
+            if let Some(current) = run_day(year, day, &examples, &input) {
+                for line in watch::diff_answers(&answers, &current) {
+                    println!("{line}");
+                }
+
+                answers = current;
+            }
+
+            previous = snapshot;
+        }
+
+        thread::sleep(args.interval);
+    }
+}
+
+fn describe_changes(previous: &Snapshot, snapshot: &Snapshot) -> String {
+    if previous.is_empty() {
+        return "First run".to_string();
+    }
+
+    let changed = snapshot
+        .iter()
+        .filter(|(path, modified)| previous.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .chain(previous.keys().filter(|path| !snapshot.contains_key(*path)))
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>();
+
+    format!("Changed: {}", changed.join(", "))
+}
+
+/// Rebuilds the runner and runs the day on each example and on the input. Returns `None` when
+/// the build failed, after cargo has shown why.
+fn run_day(year: usize, day: usize, examples: &Path, input: &Path) -> Option<RunAnswers> {
+    let built = cargo("build").status().is_ok_and(|status| status.success());
+
+    if !built {
+        println!("The build failed, waiting for the next change");
+        return None;
+    }
+
+    let mut answers = RunAnswers::new();
+
+    for example in example_files(examples) {
+        let name = example.file_name()?.to_string_lossy().to_string();
+        let path = example.display().to_string();
+
+        answers.extend(run_input(year, day, &name, &["--input", &path]));
+    }
+
+    if input.exists() {
+        let root = input.parent()?.parent()?.parent()?.display().to_string();
+
+        answers.extend(run_input(year, day, "input", &["--inputs-root", &root]));
+    }
+
+    Some(answers)
+}
+
+/// The non-empty example files, in order.
+fn example_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut files = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+            name.starts_with("example")
+                && name.ends_with(".txt")
+                && fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+        })
+        .collect::<Vec<_>>();
+
+    files.sort();
+    files
+}
+
+/// Runs the freshly built runner on one input, letting its errors through to stderr.
+fn run_input(year: usize, day: usize, name: &str, input_args: &[&str]) -> RunAnswers {
+    let output = cargo("run")
+        .arg("--")
+        .args(["--year", &year.to_string(), "--day", &day.to_string()])
+        .args(["--format", "json"])
+        .args(["--timeout", TIMEOUT_SECONDS])
+        .args(input_args)
+        .stderr(Stdio::inherit())
+        .output();
+
+    let result = output
+        .map_err(|error| format!("couldn't run cargo: {error}"))
+        .and_then(|output| watch::parse_answers(name, &String::from_utf8_lossy(&output.stdout)));
+
+    result.unwrap_or_else(|error| {
+        println!("{name}: {error}");
+        RunAnswers::new()
+    })
+}
+
+/// A quiet cargo command for the profile this runner was built with.
+fn cargo(subcommand: &str) -> Command {
+    let mut command = Command::new("cargo");
+    command.args([subcommand, "--quiet"]);
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command
+}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use utilities::{PuzzleResult, Solution};
+
+type LocationLists = (Vec<usize>, Vec<usize>);
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 1;
+
+    type Input = LocationLists;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<LocationLists> {
+        let mut left = vec![];
+        let mut right = vec![];
+
+        for line in utilities::lines(contents) {
+            let pieces: Vec<&str> = line.text.split_ascii_whitespace().collect();
+
+            let [left_val, right_val] = pieces[..] else {
+                return Err(line.error("expected two location IDs"));
+            };
+
+            left.push(line.parse::<usize>(left_val)?);
+            right.push(line.parse::<usize>(right_val)?);
+        }
+
+        Ok((left, right))
+    }
+
+    fn part_1((left, right): &LocationLists) -> PuzzleResult<usize> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
+        left.sort();
+        right.sort();
+
+        Ok(left
+            .into_iter()
+            .zip(right)
+            .fold(0, |acc, (left_val, right_val)| {
+                acc + left_val.abs_diff(right_val)
+            }))
+    }
+
+    fn part_2((left, right_vals): &LocationLists) -> PuzzleResult<usize> {
+        let mut right: HashMap<usize, usize> = HashMap::new();
+
+        for right_val in right_vals {
+            right
+                .entry(*right_val)
+                .and_modify(|rv| *rv += 1)
+                .or_insert(1);
+        }
+
+        Ok(left
+            .iter()
+            .fold(0, |acc, val| acc + val * right.get(val).unwrap_or(&0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day1::YEAR, Day1::DAY, "example.txt").unwrap();
+        let input = Day1::parse(&contents).unwrap();
+
+        assert_eq!(Day1::part_1(&input).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day1::YEAR, Day1::DAY, "input.txt").unwrap();
+        let input = Day1::parse(&contents).unwrap();
+
+        assert_eq!(Day1::part_1(&input).unwrap(), 2176849);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day1::YEAR, Day1::DAY, "example.txt").unwrap();
+        let input = Day1::parse(&contents).unwrap();
+
+        assert_eq!(Day1::part_2(&input).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day1::YEAR, Day1::DAY, "input.txt").unwrap();
+        let input = Day1::parse(&contents).unwrap();
+
+        assert_eq!(Day1::part_2(&input).unwrap(), 23384288);
+    }
+}
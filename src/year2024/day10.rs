@@ -0,0 +1,107 @@
+use utilities::grid::Grid;
+use utilities::neighbors::Neighbors;
+use utilities::point::Point;
+use utilities::search;
+use utilities::{PuzzleResult, Solution};
+
+type TopographicMap = Grid<usize>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 10;
+
+    type Input = TopographicMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<TopographicMap> {
+        Grid::parse(contents, |c| {
+            c.to_digit(10)
+                .map(|height| height as usize)
+                .ok_or(format!("invalid height '{c}'"))
+        })
+    }
+
+    fn part_1(map: &TopographicMap) -> PuzzleResult<usize> {
+        let trailheads = find_trailheads(map);
+
+        Ok(trailheads
+            .iter()
+            .map(|trailhead| calculate_trail_score(trailhead, map, false))
+            .sum())
+    }
+
+    fn part_2(map: &TopographicMap) -> PuzzleResult<usize> {
+        let trailheads = find_trailheads(map);
+
+        Ok(trailheads
+            .iter()
+            .map(|trailhead| calculate_trail_score(trailhead, map, true))
+            .sum())
+    }
+}
+
+fn find_trailheads(map: &TopographicMap) -> Vec<Point> {
+    map.find_all(&0).collect()
+}
+
+fn calculate_trail_score(trailhead: &Point, map: &TopographicMap, unique_paths: bool) -> usize {
+    // Every step climbs by one, so each path to a 9 is a shortest one.
+    let trails = search::bfs_all(
+        *trailhead,
+        |point| {
+            let height = map[*point];
+            map.neighbors_where(*point, Neighbors::four, move |next| *next == height + 1)
+        },
+        |point| map[*point] == 9,
+    );
+
+    if unique_paths {
+        trails
+            .goals()
+            .iter()
+            .map(|peak| trails.count_paths(peak))
+            .sum()
+    } else {
+        trails.goals().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day10::YEAR, Day10::DAY, "example.txt").unwrap();
+        let input = Day10::parse(&contents).unwrap();
+
+        assert_eq!(Day10::part_1(&input).unwrap(), 36);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day10::YEAR, Day10::DAY, "input.txt").unwrap();
+        let input = Day10::parse(&contents).unwrap();
+
+        assert_eq!(Day10::part_1(&input).unwrap(), 698);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day10::YEAR, Day10::DAY, "example.txt").unwrap();
+        let input = Day10::parse(&contents).unwrap();
+
+        assert_eq!(Day10::part_2(&input).unwrap(), 81);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day10::YEAR, Day10::DAY, "input.txt").unwrap();
+        let input = Day10::parse(&contents).unwrap();
+
+        assert_eq!(Day10::part_2(&input).unwrap(), 1436);
+    }
+}
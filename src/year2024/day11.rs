@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use utilities::{PuzzleResult, Solution};
+
+type Stones = Vec<usize>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 11;
+
+    type Input = Stones;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Stones> {
+        let mut stones = vec![];
+
+        for line in utilities::lines(contents) {
+            for s in line.text.split_ascii_whitespace() {
+                stones.push(line.parse(s)?);
+            }
+        }
+
+        Ok(stones)
+    }
+
+    fn part_1(stones: &Stones) -> PuzzleResult<usize> {
+        let mut stones = stones.clone();
+
+        for _ in 0..25 {
+            stones = blink_once(&stones);
+        }
+
+        Ok(stones.len())
+    }
+
+    fn part_2(stones: &Stones) -> PuzzleResult<usize> {
+        let mut stone_map: HashMap<usize, usize> = HashMap::new();
+        for stone in stones {
+            update_map(&mut stone_map, *stone, 1);
+        }
+
+        for _ in 0..75 {
+            let mut new_stone_map: HashMap<usize, usize> = HashMap::new();
+
+            for (stone, count) in stone_map {
+                let new_stones = blink_once(&vec![stone]);
+
+                for new_stone in new_stones {
+                    update_map(&mut new_stone_map, new_stone, count);
+                }
+            }
+
+            stone_map = new_stone_map;
+        }
+
+        Ok(stone_map.values().sum())
+    }
+}
+
+fn blink_once(stones: &Vec<usize>) -> Vec<usize> {
+    let mut new_stones = vec![];
+
+    for stone in stones {
+        if stone == &0 {
+            new_stones.push(1);
+            continue;
+        }
+
+        let digits = stone.ilog10() + 1;
+
+        if digits % 2 == 0 {
+            let factor = 10_usize.pow(digits / 2);
+
+            new_stones.push(stone / factor);
+            new_stones.push(stone % factor);
+
+            continue;
+        }
+
+        new_stones.push(stone * 2024);
+    }
+
+    new_stones
+}
+
+fn update_map(map: &mut HashMap<usize, usize>, stone: usize, count: usize) {
+    map.entry(stone)
+        .and_modify(|val| *val += count)
+        .or_insert(count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day11::YEAR, Day11::DAY, "example.txt").unwrap();
+        let input = Day11::parse(&contents).unwrap();
+
+        assert_eq!(Day11::part_1(&input).unwrap(), 55312);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day11::YEAR, Day11::DAY, "input.txt").unwrap();
+        let input = Day11::parse(&contents).unwrap();
+
+        assert_eq!(Day11::part_1(&input).unwrap(), 203457);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day11::YEAR, Day11::DAY, "input.txt").unwrap();
+        let input = Day11::parse(&contents).unwrap();
+
+        assert_eq!(Day11::part_2(&input).unwrap(), 241394363462435);
+    }
+}
@@ -1,24 +1,41 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::day10::get_neighbors;
-use crate::day6::Point;
+use utilities::direction::Direction;
+use utilities::grid::Grid;
+use utilities::point::Point;
+use utilities::{PuzzleResult, Solution};
 
-const DAY: usize = 12;
+pub struct Day12;
 
-pub fn run() {
-    utilities::run_puzzle(DAY, part_1, part_2);
-}
+impl Solution for Day12 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 12;
 
-type Grid = Vec<Vec<char>>;
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn part_1(contents: &str) -> usize {
-    let garden = parse_input(contents);
-    let region_map = find_regions(&garden);
+    fn parse(contents: &str) -> PuzzleResult<Grid<char>> {
+        Grid::parse(contents, Ok)
+    }
 
-    region_map
-        .values()
-        .map(|regions| calculate_fence_cost(regions, &garden))
-        .sum()
+    fn part_1(garden: &Grid<char>) -> PuzzleResult<usize> {
+        let region_map = find_regions(garden);
+
+        Ok(region_map
+            .values()
+            .map(|regions| calculate_fence_cost(regions, garden))
+            .sum())
+    }
+
+    fn part_2(garden: &Grid<char>) -> PuzzleResult<usize> {
+        let region_map = find_regions(garden);
+
+        Ok(region_map
+            .values()
+            .map(|regions| calculate_fence_cost_v2(regions, garden))
+            .sum())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -39,7 +56,7 @@ impl Region {
         self.points.len()
     }
 
-    fn perimeter(&self, garden: &Grid) -> usize {
+    fn perimeter(&self, garden: &Grid<char>) -> usize {
         let edge_map = self.build_edge_map(garden);
 
         edge_map
@@ -48,34 +65,18 @@ impl Region {
             .sum()
     }
 
-    fn build_edge_map(&self, garden: &Grid) -> HashMap<Point, Vec<bool>> {
+    fn build_edge_map(&self, garden: &Grid<char>) -> HashMap<Point, Vec<bool>> {
         let mut edge_map = HashMap::new();
 
         for point in &self.points {
-            let mut edges = vec![false; 4];
-
-            // Up
-            if point.row == 0 || garden[point.row - 1][point.col] != self.plant_type {
-                edges[0] = true;
-            }
-
-            // Right
-            if point.col == garden[0].len() - 1
-                || garden[point.row][point.col + 1] != self.plant_type
-            {
-                edges[1] = true;
-            }
-
-            // Down
-            if point.row == garden.len() - 1 || garden[point.row + 1][point.col] != self.plant_type
-            {
-                edges[2] = true;
-            }
-
-            // Left
-            if point.col == 0 || garden[point.row][point.col - 1] != self.plant_type {
-                edges[3] = true;
-            }
+            // Up, right, down and left, which have an edge unless the same plant is next to it.
+            let edges = Direction::all()
+                .map(|direction| {
+                    garden
+                        .offset(*point, direction.offset())
+                        .is_none_or(|neighbor| garden[neighbor] != self.plant_type)
+                })
+                .collect();
 
             edge_map.insert(*point, edges);
         }
@@ -83,88 +84,63 @@ impl Region {
         edge_map
     }
 
-    fn is_point_adjacent(&self, point: &Point, garden: &Grid) -> bool {
-        for neighbor in get_neighbors(point, garden) {
-            if self.points.contains(&neighbor) {
-                return true;
-            }
-        }
-
-        false
+    fn is_point_adjacent(&self, point: &Point, garden: &Grid<char>) -> bool {
+        garden
+            .neighbors(*point)
+            .any(|neighbor| self.points.contains(&neighbor))
     }
 }
 
-fn parse_input(contents: &str) -> Grid {
-    contents
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
-}
-
-fn find_regions(garden: &Grid) -> HashMap<char, Vec<Region>> {
+fn find_regions(garden: &Grid<char>) -> HashMap<char, Vec<Region>> {
     let mut region_map: HashMap<char, Vec<Region>> = HashMap::new();
 
-    for (row, row_data) in garden.iter().enumerate() {
-        for (col, plant_type) in row_data.iter().enumerate() {
-            let point = Point::new(row, col);
-
-            region_map
-                .entry(*plant_type)
-                .and_modify(|regions| {
-                    let mut adjacent_regions = vec![];
+    for (point, plant_type) in garden.iter() {
+        region_map
+            .entry(*plant_type)
+            .and_modify(|regions| {
+                let mut adjacent_regions = vec![];
 
-                    for (i, region) in regions.iter_mut().enumerate() {
-                        if region.is_point_adjacent(&point, garden) {
-                            adjacent_regions.push(i);
-                        }
+                for (i, region) in regions.iter_mut().enumerate() {
+                    if region.is_point_adjacent(&point, garden) {
+                        adjacent_regions.push(i);
                     }
+                }
 
-                    if !adjacent_regions.is_empty() {
-                        let main_region_idx = adjacent_regions[0];
-                        regions[main_region_idx].points.insert(point);
-
-                        // All other regions are also adjacent and need to be merged.
-                        for region_idx in adjacent_regions.iter().skip(1) {
-                            let points = regions[*region_idx].points.clone();
-                            regions[*region_idx].points.clear();
-                            regions[main_region_idx].points.extend(points);
-                        }
-                    } else {
-                        regions.push(Region::new(*plant_type, point));
+                if !adjacent_regions.is_empty() {
+                    let main_region_idx = adjacent_regions[0];
+                    regions[main_region_idx].points.insert(point);
+
+                    // All other regions are also adjacent and need to be merged.
+                    for region_idx in adjacent_regions.iter().skip(1) {
+                        let points = regions[*region_idx].points.clone();
+                        regions[*region_idx].points.clear();
+                        regions[main_region_idx].points.extend(points);
                     }
-                })
-                .or_insert(vec![Region::new(*plant_type, point)]);
-        }
+                } else {
+                    regions.push(Region::new(*plant_type, point));
+                }
+            })
+            .or_insert(vec![Region::new(*plant_type, point)]);
     }
 
     region_map
 }
 
-fn calculate_fence_cost(regions: &[Region], garden: &Grid) -> usize {
+fn calculate_fence_cost(regions: &[Region], garden: &Grid<char>) -> usize {
     regions
         .iter()
         .map(|region| region.area() * region.perimeter(garden))
         .sum()
 }
 
-fn part_2(contents: &str) -> usize {
-    let garden = parse_input(contents);
-    let region_map = find_regions(&garden);
-
-    region_map
-        .values()
-        .map(|regions| calculate_fence_cost_v2(regions, &garden))
-        .sum()
-}
-
-fn calculate_fence_cost_v2(regions: &[Region], garden: &Grid) -> usize {
+fn calculate_fence_cost_v2(regions: &[Region], garden: &Grid<char>) -> usize {
     regions
         .iter()
-        .map(|region| region.area() * count_region_edges(region, &garden))
+        .map(|region| region.area() * count_region_edges(region, garden))
         .sum()
 }
 
-fn count_region_edges(region: &Region, garden: &Grid) -> usize {
+fn count_region_edges(region: &Region, garden: &Grid<char>) -> usize {
     if region.points.is_empty() {
         return 0;
     }
@@ -274,43 +250,49 @@ mod tests {
 
     #[test]
     fn test_example_part_1() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day12::YEAR, Day12::DAY, "example.txt").unwrap();
+        let input = Day12::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 140);
+        assert_eq!(Day12::part_1(&input).unwrap(), 140);
     }
 
     #[test]
     fn test_example2_part_1() {
-        let contents = utilities::read_file_data(DAY, "example2.txt");
+        let contents = utilities::read_file_data(Day12::YEAR, Day12::DAY, "example2.txt").unwrap();
+        let input = Day12::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 1930);
+        assert_eq!(Day12::part_1(&input).unwrap(), 1930);
     }
 
     #[test]
     fn test_input_part_1() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day12::YEAR, Day12::DAY, "input.txt").unwrap();
+        let input = Day12::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 1434856);
+        assert_eq!(Day12::part_1(&input).unwrap(), 1434856);
     }
 
     #[test]
     fn test_example_part_2() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day12::YEAR, Day12::DAY, "example.txt").unwrap();
+        let input = Day12::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 80);
+        assert_eq!(Day12::part_2(&input).unwrap(), 80);
     }
 
     #[test]
     fn test_example2_part_2() {
-        let contents = utilities::read_file_data(DAY, "example2.txt");
+        let contents = utilities::read_file_data(Day12::YEAR, Day12::DAY, "example2.txt").unwrap();
+        let input = Day12::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 1206);
+        assert_eq!(Day12::part_2(&input).unwrap(), 1206);
     }
 
     #[test]
     fn test_input_part_2() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day12::YEAR, Day12::DAY, "input.txt").unwrap();
+        let input = Day12::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 891106);
+        assert_eq!(Day12::part_2(&input).unwrap(), 891106);
     }
 }
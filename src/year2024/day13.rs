@@ -0,0 +1,156 @@
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 13;
+
+    type Input = ClawMachines;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<ClawMachines> {
+        let mut a_buttons = vec![];
+        let mut b_buttons = vec![];
+        let mut prizes = vec![];
+
+        for line in utilities::lines(contents) {
+            if line.text.is_empty() {
+                continue;
+            }
+
+            let Some((label, values)) = line.text.split_once(':') else {
+                return Err(line.error("expected a button or prize description"));
+            };
+
+            let values = values
+                .split(',')
+                .map(|s| s.trim())
+                .map(|s| {
+                    let value = s.split(&['+', '=']).next_back().unwrap_or(s);
+
+                    line.parse::<usize>(value)
+                })
+                .collect::<PuzzleResult<Vec<_>>>()?;
+
+            let [x, y] = values[..] else {
+                return Err(line.error("expected an X and a Y value"));
+            };
+            let values = (x, y);
+
+            match label {
+                "Button A" => a_buttons.push(values),
+                "Button B" => b_buttons.push(values),
+                "Prize" => prizes.push(values),
+                _ => return Err(line.error_at(label, format!("unexpected label '{label}'"))),
+            };
+        }
+
+        if a_buttons.len() != b_buttons.len() || a_buttons.len() != prizes.len() {
+            return Err(PuzzleError::parse(
+                "every claw machine needs an A button, a B button and a prize",
+            ));
+        }
+
+        let mut claw_machines = vec![];
+
+        for i in 0..a_buttons.len() {
+            claw_machines.push(ClawMachine::new(a_buttons[i], b_buttons[i], prizes[i]));
+        }
+
+        Ok(claw_machines)
+    }
+
+    fn part_1(claw_machines: &ClawMachines) -> PuzzleResult<usize> {
+        Ok(claw_machines.iter().map(play_claw_machine).sum())
+    }
+
+    fn part_2(claw_machines: &ClawMachines) -> PuzzleResult<usize> {
+        Ok(claw_machines
+            .iter()
+            .map(|claw_machine| play_claw_machine(&claw_machine.with_prize_offset(PRIZE_OFFSET)))
+            .sum())
+    }
+}
+
+const PRIZE_OFFSET: usize = 10000000000000;
+
+type ClawMachines = Vec<ClawMachine>;
+
+pub struct ClawMachine {
+    a_button: (usize, usize),
+    b_button: (usize, usize),
+    prize: (usize, usize),
+}
+
+impl ClawMachine {
+    fn new(a_button: (usize, usize), b_button: (usize, usize), prize: (usize, usize)) -> Self {
+        Self {
+            a_button,
+            b_button,
+            prize,
+        }
+    }
+
+    fn with_prize_offset(&self, offset: usize) -> Self {
+        let prize = (self.prize.0 + offset, self.prize.1 + offset);
+
+        Self::new(self.a_button, self.b_button, prize)
+    }
+}
+
+fn play_claw_machine(claw_machine: &ClawMachine) -> usize {
+    let (ax, ay) = claw_machine.a_button;
+    let (bx, by) = claw_machine.b_button;
+    let (px, py) = claw_machine.prize;
+
+    let num = (py * ax).abs_diff(px * ay);
+    let denom = (ax * by).abs_diff(ay * bx);
+
+    if denom == 0 || num % denom != 0 {
+        return 0;
+    }
+
+    let m = num / denom;
+
+    let num = px - (m * bx);
+    let denom = ax;
+
+    if denom == 0 || num % denom != 0 {
+        return 0;
+    }
+
+    let n = num / denom;
+
+    n * 3 + m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day13::YEAR, Day13::DAY, "example.txt").unwrap();
+        let input = Day13::parse(&contents).unwrap();
+
+        assert_eq!(Day13::part_1(&input).unwrap(), 480);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day13::YEAR, Day13::DAY, "input.txt").unwrap();
+        let input = Day13::parse(&contents).unwrap();
+
+        assert_eq!(Day13::part_1(&input).unwrap(), 36838);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day13::YEAR, Day13::DAY, "input.txt").unwrap();
+        let input = Day13::parse(&contents).unwrap();
+
+        assert_eq!(Day13::part_2(&input).unwrap(), 83029436920891);
+    }
+}
@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+
+use utilities::point::{Point, Vector};
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+const ROOM_WIDTH: usize = 101;
+const ROOM_HEIGHT: usize = 103;
+
+type Robots = Vec<Robot>;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 14;
+
+    type Input = Robots;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Robots> {
+        utilities::lines(contents)
+            .map(|line| {
+                let pieces = line
+                    .text
+                    .split_ascii_whitespace()
+                    .flat_map(|pieces| {
+                        pieces
+                            .split(&['p', 'v', '=', ','])
+                            .filter(|val| !val.is_empty())
+                            .map(|val| line.parse::<isize>(val))
+                    })
+                    .collect::<PuzzleResult<Vec<_>>>()?;
+
+                let [x, y, velocity_x, velocity_y] = pieces[..] else {
+                    return Err(line.error("expected a robot like p=0,4 v=3,-3"));
+                };
+
+                if x < 0 || y < 0 || x as usize >= ROOM_WIDTH || y as usize >= ROOM_HEIGHT {
+                    return Err(line.error("robots must start inside the room"));
+                }
+
+                let start = Point::from_xy(x as usize, y as usize);
+                let velocity = Vector::from_xy(velocity_x, velocity_y);
+
+                Ok(Robot::new(start, velocity))
+            })
+            .collect()
+    }
+
+    fn part_1(robots: &Robots) -> PuzzleResult<usize> {
+        part_1_with_bounds(robots, ROOM_WIDTH, ROOM_HEIGHT)
+    }
+
+    fn part_2(robots: &Robots) -> PuzzleResult<usize> {
+        let mut positions = vec![];
+        let mut velocities = vec![];
+
+        for robot in robots {
+            positions.push(robot.start);
+            velocities.push(robot.velocity);
+        }
+
+        let initial_positions = positions.clone();
+
+        let mut iteration = 0;
+        let mut safety_scores = vec![];
+
+        loop {
+            if positions == initial_positions && iteration != 0 {
+                break;
+            }
+
+            let score = calculate_safety_score(&positions, ROOM_WIDTH, ROOM_HEIGHT);
+            safety_scores.push(score);
+
+            for i in 0..positions.len() {
+                positions[i] =
+                    step_with_iterations(&positions[i], velocities[i], ROOM_WIDTH, ROOM_HEIGHT, 1);
+            }
+
+            iteration += 1;
+        }
+
+        let (idx, _) = safety_scores
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .ok_or(PuzzleError::solve("no robots to arrange"))?;
+
+        Ok(idx)
+    }
+}
+
+fn part_1_with_bounds(
+    robots: &[Robot],
+    room_width: usize,
+    room_height: usize,
+) -> PuzzleResult<usize> {
+    let robot_positions = robots
+        .iter()
+        .map(|robot| {
+            step_with_iterations(&robot.start, robot.velocity, room_width, room_height, 100)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(calculate_safety_score(
+        &robot_positions,
+        room_width,
+        room_height,
+    ))
+}
+
+#[derive(Debug)]
+pub struct Robot {
+    start: Point,
+    velocity: Vector,
+}
+
+impl Robot {
+    fn new(start: Point, velocity: Vector) -> Self {
+        Self { start, velocity }
+    }
+}
+
+fn calculate_safety_score(
+    robot_positions: &Vec<Point>,
+    room_width: usize,
+    room_height: usize,
+) -> usize {
+    let center_vertical = room_width / 2;
+    let center_horizontal = room_height / 2;
+
+    let mut upper_left_count = 0;
+    let mut upper_right_count = 0;
+    let mut lower_left_count = 0;
+    let mut lower_right_count = 0;
+
+    for robot_position in robot_positions {
+        match (
+            robot_position.row.cmp(&center_horizontal),
+            robot_position.col.cmp(&center_vertical),
+        ) {
+            (Ordering::Less, Ordering::Less) => upper_left_count += 1,
+            (Ordering::Less, Ordering::Greater) => upper_right_count += 1,
+            (Ordering::Greater, Ordering::Less) => lower_left_count += 1,
+            (Ordering::Greater, Ordering::Greater) => lower_right_count += 1,
+            _ => {}
+        };
+    }
+
+    upper_left_count * upper_right_count * lower_left_count * lower_right_count
+}
+
+fn step_with_iterations(
+    start: &Point,
+    velocity: Vector,
+    room_width: usize,
+    room_height: usize,
+    iterations: usize,
+) -> Point {
+    start.wrapping_add(velocity * iterations as isize, room_width, room_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utilities::neighbors::Neighbors;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day14::YEAR, Day14::DAY, "example.txt").unwrap();
+        let input = Day14::parse(&contents).unwrap();
+
+        assert_eq!(part_1_with_bounds(&input, 11, 7).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_robot_outside_room() {
+        assert!(Day14::parse("p=101,0 v=1,1\n").is_err());
+        assert!(Day14::parse("p=0,103 v=1,1\n").is_err());
+    }
+
+    #[test]
+    fn test_robots_meet_across_edges() {
+        let robots = Day14::parse("p=10,6 v=1,1\np=10,6 v=0,0\n").unwrap();
+        let moved = step_with_iterations(&robots[0].start, robots[0].velocity, 11, 7, 1);
+
+        assert_eq!(moved, Point::new(0, 0));
+        assert!(Neighbors::eight(moved, 11, 7)
+            .wrapping()
+            .any(|neighbor| neighbor == robots[1].start));
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day14::YEAR, Day14::DAY, "input.txt").unwrap();
+        let input = Day14::parse(&contents).unwrap();
+
+        assert_eq!(Day14::part_1(&input).unwrap(), 226179492);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day14::YEAR, Day14::DAY, "input.txt").unwrap();
+        let input = Day14::parse(&contents).unwrap();
+
+        assert_eq!(Day14::part_2(&input).unwrap(), 7502);
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    ops::Range,
+};
+
+use utilities::direction::Direction;
+use utilities::grid::Grid;
+use utilities::point::Point;
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 15;
+
+    type Input = (Grid<char>, Vec<Direction>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<(Grid<char>, Vec<Direction>)> {
+        let mut lines = utilities::lines(contents);
+
+        let grid = Grid::from_lines(lines.by_ref().take_while(|line| !line.text.is_empty()), Ok)?;
+        let mut moves = vec![];
+
+        for line in lines {
+            for (idx, c) in line.text.char_indices() {
+                match Direction::try_from(c) {
+                    Ok(direction) if direction.arrow() == c => moves.push(direction),
+                    _ => return Err(line.error_at(&line.text[idx..], "invalid robot move")),
+                }
+            }
+        }
+
+        Ok((grid, moves))
+    }
+
+    fn part_1((grid, moves): &(Grid<char>, Vec<Direction>)) -> PuzzleResult<usize> {
+        let grid = apply_moves(grid.clone(), moves)?;
+        let boxes = find_boxes(&grid);
+
+        Ok(boxes.iter().map(|point| 100 * point.row + point.col).sum())
+    }
+
+    fn part_2((grid, moves): &(Grid<char>, Vec<Direction>)) -> PuzzleResult<usize> {
+        let grid = expand_grid(grid);
+
+        let grid = apply_moves_v2(grid, moves)?;
+        let boxes = find_boxes(&grid);
+
+        Ok(boxes.iter().map(|point| 100 * point.row + point.col).sum())
+    }
+}
+
+fn apply_moves(start_grid: Grid<char>, moves: &[Direction]) -> PuzzleResult<Grid<char>> {
+    let mut grid = start_grid;
+
+    let mut robot_position = find_robot(&grid)?;
+    grid[robot_position] = '.';
+
+    for robot_move in moves {
+        robot_position = move_robot(&robot_position, *robot_move, &mut grid);
+    }
+
+    Ok(grid)
+}
+
+fn find_robot(grid: &Grid<char>) -> PuzzleResult<Point> {
+    grid.find(&'@')
+        .ok_or_else(|| PuzzleError::parse("couldn't find the robot starting position '@'"))
+}
+
+fn move_robot(position: &Point, direction: Direction, grid: &mut Grid<char>) -> Point {
+    let next_points = get_points_in_direction(position, direction, grid);
+
+    if next_points.is_empty() {
+        return *position;
+    }
+
+    let box_count = next_points
+        .iter()
+        .filter(|point| grid[**point] == 'O')
+        .count();
+
+    if box_count == next_points.len() {
+        return *position;
+    }
+
+    // There is space, so try to push the boxes ahead if needed.
+    let next_point = next_points[0];
+
+    if grid[next_point] != 'O' {
+        return next_point;
+    }
+
+    grid[next_point] = '.';
+
+    for point in next_points.iter().skip(1) {
+        if grid[*point] == '.' {
+            grid[*point] = 'O';
+            break;
+        }
+    }
+
+    next_point
+}
+
+fn get_points_in_direction(
+    position: &Point,
+    direction: Direction,
+    grid: &Grid<char>,
+) -> Vec<Point> {
+    let mut row_rev = false;
+    let mut col_rev = false;
+
+    let (row_range, col_range) = match direction {
+        Direction::North => {
+            row_rev = true;
+            (0..position.row, position.col..(position.col + 1))
+        }
+        Direction::East => (
+            position.row..(position.row + 1),
+            (position.col + 1)..grid.width(),
+        ),
+        Direction::South => (
+            (position.row + 1)..grid.height(),
+            position.col..(position.col + 1),
+        ),
+        Direction::West => {
+            col_rev = true;
+            (position.row..(position.row + 1), (0..position.col))
+        }
+    };
+
+    if row_range.is_empty() || col_range.is_empty() {
+        return vec![];
+    }
+
+    let mut points = vec![];
+
+    for point in get_points_iter(row_range, col_range, row_rev, col_rev) {
+        if grid[point] == '#' {
+            break;
+        }
+
+        points.push(point);
+    }
+
+    points
+}
+
+fn get_points_iter(
+    row_range: Range<usize>,
+    col_range: Range<usize>,
+    row_rev: bool,
+    col_rev: bool,
+) -> impl Iterator<Item = Point> {
+    let use_row_iter = row_range.len() != 1;
+    let (rev_iter, other_constant) = if use_row_iter {
+        (row_rev, col_range.start)
+    } else {
+        (col_rev, row_range.start)
+    };
+
+    let iter: Box<dyn Iterator<Item = usize>> = if use_row_iter {
+        if rev_iter {
+            Box::new(row_range.into_iter().rev())
+        } else {
+            Box::new(row_range.into_iter())
+        }
+    } else if rev_iter {
+        Box::new(col_range.into_iter().rev())
+    } else {
+        Box::new(col_range.into_iter())
+    };
+
+    iter.map(move |val| {
+        if use_row_iter {
+            Point::new(val, other_constant)
+        } else {
+            Point::new(other_constant, val)
+        }
+    })
+}
+
+fn find_boxes(grid: &Grid<char>) -> Vec<Point> {
+    grid.iter()
+        .filter(|(_, c)| **c == 'O' || **c == '[')
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn expand_grid(grid: &Grid<char>) -> Grid<char> {
+    let mut expanded = Grid::new(grid.width() * 2, grid.height(), '.');
+
+    for (point, c) in grid.iter() {
+        let [left, right] = match c {
+            'O' => ['[', ']'],
+            '@' => ['@', '.'],
+            _ => [*c, *c],
+        };
+
+        expanded[Point::new(point.row, point.col * 2)] = left;
+        expanded[Point::new(point.row, point.col * 2 + 1)] = right;
+    }
+
+    expanded
+}
+
+fn apply_moves_v2(start_grid: Grid<char>, moves: &[Direction]) -> PuzzleResult<Grid<char>> {
+    let mut grid = start_grid;
+
+    let mut robot_position = find_robot(&grid)?;
+    grid[robot_position] = '.';
+
+    for robot_move in moves {
+        robot_position = move_robot_v2(&robot_position, *robot_move, &mut grid);
+    }
+
+    Ok(grid)
+}
+
+fn move_robot_v2(position: &Point, direction: Direction, grid: &mut Grid<char>) -> Point {
+    let Some(next_point) = grid.offset(*position, direction.offset()) else {
+        return *position;
+    };
+
+    let next_position_item = grid[next_point];
+
+    // If there is a free space or a wall, then already know what to do.
+    if next_position_item == '.' {
+        return next_point;
+    } else if next_position_item == '#' {
+        return *position;
+    }
+
+    // If it's a box, try to move it and any other boxes on the other side of it.
+    let box_position = match next_position_item {
+        '[' => next_point,
+        ']' => Point::new(next_point.row, next_point.col - 1),
+        _ => unreachable!(),
+    };
+
+    if let Some(boxes) = find_boxes_to_move(grid, &box_position, direction) {
+        move_boxes(grid, direction, &boxes);
+
+        next_point
+    } else {
+        *position
+    }
+}
+
+fn find_boxes_to_move(
+    grid: &Grid<char>,
+    box_position: &Point,
+    direction: Direction,
+) -> Option<HashSet<Point>> {
+    // Ran into a box, try to move them.
+    let mut boxes = HashSet::new();
+
+    let mut box_queue = VecDeque::new();
+    box_queue.push_back(*box_position);
+
+    while let Some(box_position) = box_queue.pop_front() {
+        if !boxes.insert(box_position) {
+            continue;
+        }
+
+        let next_position = match direction {
+            Direction::East => grid.offset(
+                Point::new(box_position.row, box_position.col + 1),
+                direction.offset(),
+            ),
+            _ => grid.offset(box_position, direction.offset()),
+        };
+
+        let Some(next_position) = next_position else {
+            // Off the edge, can't push the boxes.
+            return None;
+        };
+
+        match grid[next_position] {
+            '#' => return None,
+            '[' => box_queue.push_back(next_position),
+            ']' => box_queue.push_back(Point::new(next_position.row, next_position.col - 1)),
+            _ => {}
+        }
+
+        if matches!(direction, Direction::North | Direction::South) {
+            // For up and down, need to also consider the point above or below the right side of the box.
+            let other_next_position = Point::new(next_position.row, next_position.col + 1);
+
+            match grid[other_next_position] {
+                '#' => return None,
+                '[' => box_queue.push_back(other_next_position),
+                _ => {}
+            }
+        }
+    }
+
+    Some(boxes)
+}
+
+fn move_boxes(grid: &mut Grid<char>, direction: Direction, boxes: &HashSet<Point>) {
+    // First clear out old boxes, and then fill in the new positions.
+    for box_point in boxes.iter() {
+        grid[*box_point] = '.';
+        grid[Point::new(box_point.row, box_point.col + 1)] = '.';
+    }
+
+    for box_point in boxes.iter() {
+        let new_point = grid
+            .offset(*box_point, direction.offset())
+            .expect("boxes only move inside the walls");
+
+        grid[new_point] = '[';
+        grid[Point::new(new_point.row, new_point.col + 1)] = ']';
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day15::YEAR, Day15::DAY, "example.txt").unwrap();
+        let input = Day15::parse(&contents).unwrap();
+
+        assert_eq!(Day15::part_1(&input).unwrap(), 10092);
+    }
+
+    #[test]
+    fn test_example2_part_1() {
+        let contents = utilities::read_file_data(Day15::YEAR, Day15::DAY, "example2.txt").unwrap();
+        let input = Day15::parse(&contents).unwrap();
+
+        assert_eq!(Day15::part_1(&input).unwrap(), 2028);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day15::YEAR, Day15::DAY, "input.txt").unwrap();
+        let input = Day15::parse(&contents).unwrap();
+
+        assert_eq!(Day15::part_1(&input).unwrap(), 1495147);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day15::YEAR, Day15::DAY, "example.txt").unwrap();
+        let input = Day15::parse(&contents).unwrap();
+
+        assert_eq!(Day15::part_2(&input).unwrap(), 9021);
+    }
+
+    #[test]
+    fn test_example3_part_2() {
+        let contents = utilities::read_file_data(Day15::YEAR, Day15::DAY, "example3.txt").unwrap();
+        let input = Day15::parse(&contents).unwrap();
+
+        assert_eq!(Day15::part_2(&input).unwrap(), 618);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day15::YEAR, Day15::DAY, "input.txt").unwrap();
+        let input = Day15::parse(&contents).unwrap();
+
+        assert_eq!(Day15::part_2(&input).unwrap(), 1524905);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use utilities::direction::Direction;
+use utilities::grid::Grid;
+use utilities::point::Point;
+use utilities::search;
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 16;
+
+    type Input = (Grid<char>, Point, Point);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<(Grid<char>, Point, Point)> {
+        let grid = Grid::parse(contents, Ok)?;
+
+        let start = grid
+            .find(&'S')
+            .ok_or(PuzzleError::parse("no start tile 'S' found"))?;
+        let end = grid
+            .find(&'E')
+            .ok_or(PuzzleError::parse("no end tile 'E' found"))?;
+
+        Ok((grid, start, end))
+    }
+
+    fn part_1((grid, start, end): &(Grid<char>, Point, Point)) -> PuzzleResult<usize> {
+        find_shortest_path(grid, start, end)
+    }
+
+    fn part_2((grid, start, end): &(Grid<char>, Point, Point)) -> PuzzleResult<usize> {
+        find_shortest_path_v2(grid, start, end)
+    }
+}
+
+fn find_shortest_path(grid: &Grid<char>, start: &Point, end: &Point) -> PuzzleResult<usize> {
+    let path = search::dijkstra(
+        (*start, Direction::East),
+        |reindeer| get_moves(reindeer, grid),
+        |(point, _)| point == end,
+    )
+    .ok_or(PuzzleError::solve("did not find a path to the end"))?;
+
+    Ok(path.cost)
+}
+
+fn find_shortest_path_v2(grid: &Grid<char>, start: &Point, end: &Point) -> PuzzleResult<usize> {
+    let paths = search::dijkstra_all(
+        (*start, Direction::East),
+        |reindeer| get_moves(reindeer, grid),
+        |(point, _)| point == end,
+    );
+
+    if paths.cost().is_none() {
+        return Err(PuzzleError::solve("did not find a path to the end"));
+    }
+
+    // The best paths can cross the same tile facing different ways, so only count it once.
+    let spaces = paths
+        .states()
+        .into_iter()
+        .map(|(point, _)| point)
+        .collect::<HashSet<_>>();
+
+    Ok(spaces.len())
+}
+
+/// Steps to each open neighbor, with the score of turning to face it first.
+fn get_moves<'a>(
+    &(point, direction): &(Point, Direction),
+    grid: &'a Grid<char>,
+) -> impl Iterator<Item = ((Point, Direction), usize)> + 'a {
+    Direction::all().filter_map(move |new_direction| {
+        let neighbor = grid
+            .offset(point, new_direction.offset())
+            .filter(|neighbor| grid[*neighbor] != '#')?;
+
+        let score = if new_direction == direction {
+            1
+        } else if new_direction == direction.opposite() {
+            2000 + 1
+        } else {
+            1000 + 1
+        };
+
+        Some(((neighbor, new_direction), score))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day16::YEAR, Day16::DAY, "example.txt").unwrap();
+        let input = Day16::parse(&contents).unwrap();
+
+        assert_eq!(Day16::part_1(&input).unwrap(), 7036);
+    }
+
+    #[test]
+    fn test_example2_part_1() {
+        let contents = utilities::read_file_data(Day16::YEAR, Day16::DAY, "example2.txt").unwrap();
+        let input = Day16::parse(&contents).unwrap();
+
+        assert_eq!(Day16::part_1(&input).unwrap(), 11048);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day16::YEAR, Day16::DAY, "input.txt").unwrap();
+        let input = Day16::parse(&contents).unwrap();
+
+        assert_eq!(Day16::part_1(&input).unwrap(), 104516);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day16::YEAR, Day16::DAY, "example.txt").unwrap();
+        let input = Day16::parse(&contents).unwrap();
+
+        assert_eq!(Day16::part_2(&input).unwrap(), 45);
+    }
+
+    #[test]
+    fn test_example2_part_2() {
+        let contents = utilities::read_file_data(Day16::YEAR, Day16::DAY, "example2.txt").unwrap();
+        let input = Day16::parse(&contents).unwrap();
+
+        assert_eq!(Day16::part_2(&input).unwrap(), 64);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day16::YEAR, Day16::DAY, "input.txt").unwrap();
+        let input = Day16::parse(&contents).unwrap();
+
+        assert_eq!(Day16::part_2(&input).unwrap(), 545);
+    }
+}
@@ -0,0 +1,272 @@
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 17;
+
+    type Input = Computer;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Computer> {
+        let mut registers = vec![];
+        let mut instructions = vec![];
+
+        let mut done_registers = false;
+
+        for line in utilities::lines(contents) {
+            if line.text.is_empty() {
+                done_registers = true;
+                continue;
+            }
+
+            let Some((_, line_data)) = line.text.split_once(':') else {
+                return Err(line.error("expected a register or program line"));
+            };
+            let line_data = line_data.trim();
+
+            if !done_registers {
+                let register = line.parse::<usize>(line_data)?;
+
+                registers.push(register);
+            } else {
+                for s in line_data.split(',') {
+                    let instruction = line.parse::<usize>(s)?;
+
+                    if instruction > 7 {
+                        return Err(line.error_at(s, "instructions must be 3-bit numbers"));
+                    }
+
+                    instructions.push(instruction);
+                }
+            }
+        }
+
+        let [a, b, c] = registers[..] else {
+            return Err(PuzzleError::parse("expected registers A, B and C"));
+        };
+
+        if instructions.len() % 2 != 0 {
+            return Err(PuzzleError::parse("every instruction needs an operand"));
+        }
+
+        Ok(Computer::new(a, b, c, instructions))
+    }
+
+    fn part_1(computer: &Computer) -> PuzzleResult<String> {
+        let mut computer = computer.clone();
+        computer.run();
+
+        Ok(computer.get_output())
+    }
+
+    fn part_2(computer: &Computer) -> PuzzleResult<usize> {
+        let mut computer = computer.clone();
+
+        calculate_value(&mut computer)
+    }
+}
+
+/// The 3-bit computer, with its registers, program and the output so far.
+#[derive(Clone)]
+pub struct Computer {
+    a: usize,
+    b: usize,
+    c: usize,
+    ip: usize,
+    instructions: Vec<usize>,
+    output: Vec<usize>,
+    initial_a: usize,
+    initial_b: usize,
+    initial_c: usize,
+}
+
+impl Computer {
+    /// A computer with registers `a`, `b` and `c` about to run `instructions`.
+    pub fn new(a: usize, b: usize, c: usize, instructions: Vec<usize>) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            ip: 0,
+            instructions,
+            output: vec![],
+            initial_a: a,
+            initial_b: b,
+            initial_c: c,
+        }
+    }
+
+    /// Runs the program until it halts.
+    pub fn run(&mut self) {
+        while self.step(false) {}
+    }
+
+    fn step(&mut self, exit_on_branch: bool) -> bool {
+        let opcode = self.instructions[self.ip];
+        let operand = self.instructions[self.ip + 1];
+        let combo_operand = self.get_combo_operand(operand);
+
+        let mut advance_ip = true;
+
+        match opcode {
+            0 => self.a = self.dv(combo_operand),
+            1 => self.b ^= operand,
+            2 => self.b = combo_operand % 8,
+            3 => {
+                if self.a != 0 {
+                    self.ip = operand;
+                    advance_ip = false;
+
+                    if exit_on_branch {
+                        return false;
+                    }
+                }
+            }
+            4 => self.b ^= self.c,
+            5 => self.output.push(combo_operand % 8),
+            6 => self.b = self.dv(combo_operand),
+            7 => self.c = self.dv(combo_operand),
+            _ => unreachable!("Illegal opcode"),
+        }
+
+        if advance_ip {
+            self.ip += 2;
+        }
+
+        self.ip < self.instructions.len()
+    }
+
+    fn get_combo_operand(&self, operand: usize) -> usize {
+        if operand <= 3 {
+            operand
+        } else {
+            match operand {
+                4 => self.a,
+                5 => self.b,
+                6 => self.c,
+                _ => panic!("Illegal operand"),
+            }
+        }
+    }
+
+    fn dv(&self, combo_operand: usize) -> usize {
+        self.a / 2_usize.pow(combo_operand as u32)
+    }
+
+    /// The values output so far, separated by commas.
+    pub fn get_output(&self) -> String {
+        self.output
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Puts the registers back to their starting values and clears the output.
+    pub fn reset(&mut self) {
+        self.output.clear();
+        self.ip = 0;
+
+        self.a = self.initial_a;
+        self.b = self.initial_b;
+        self.c = self.initial_c;
+    }
+}
+
+fn calculate_value(computer: &mut Computer) -> PuzzleResult<usize> {
+    let instructions = computer
+        .instructions
+        .iter()
+        .rev()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let result = calculate_value_helper(computer, &instructions, 0, 0).ok_or(
+        PuzzleError::solve("no value of register A makes the program output itself"),
+    )?;
+
+    // Validate
+    computer.reset();
+    computer.a = result;
+    computer.run();
+
+    if computer.output != computer.instructions {
+        return Err(PuzzleError::solve(format!("incorrect value {result}")));
+    }
+
+    Ok(result)
+}
+
+fn calculate_value_helper(
+    computer: &mut Computer,
+    instructions: &Vec<usize>,
+    value: usize,
+    idx: usize,
+) -> Option<usize> {
+    if idx == instructions.len() {
+        return Some(value);
+    }
+
+    let instruction = instructions[idx];
+
+    for i in 0..8 {
+        let current_value = value << 3 | i;
+
+        computer.reset();
+        computer.a = current_value;
+
+        while computer.step(true) {}
+
+        if let Some(last) = computer.output.first() {
+            if last == &instruction {
+                let result = calculate_value_helper(computer, instructions, current_value, idx + 1);
+
+                if result.is_some() {
+                    return result;
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day17::YEAR, Day17::DAY, "example.txt").unwrap();
+        let input = Day17::parse(&contents).unwrap();
+
+        assert_eq!(Day17::part_1(&input).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day17::YEAR, Day17::DAY, "input.txt").unwrap();
+        let input = Day17::parse(&contents).unwrap();
+
+        assert_eq!(Day17::part_1(&input).unwrap(), "7,5,4,3,4,5,3,4,6");
+    }
+
+    #[test]
+    fn test_example2_part_2() {
+        let contents = utilities::read_file_data(Day17::YEAR, Day17::DAY, "example2.txt").unwrap();
+        let input = Day17::parse(&contents).unwrap();
+
+        assert_eq!(Day17::part_2(&input).unwrap(), 117440);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day17::YEAR, Day17::DAY, "input.txt").unwrap();
+        let input = Day17::parse(&contents).unwrap();
+
+        assert_eq!(Day17::part_2(&input).unwrap(), 164278899142333);
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use utilities::grid::Grid;
+use utilities::neighbors::Neighbors;
+use utilities::point::Point;
+use utilities::search::{self, Path};
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+type Bytes = Vec<Point>;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 18;
+
+    type Input = Bytes;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> PuzzleResult<Bytes> {
+        utilities::lines(contents)
+            .map(|line| {
+                let Some((x, y)) = line.text.split_once(',') else {
+                    return Err(line.error("expected a byte position like 5,4"));
+                };
+
+                Ok(Point::from_xy(line.parse(x)?, line.parse(y)?))
+            })
+            .collect()
+    }
+
+    fn part_1(bytes: &Bytes) -> PuzzleResult<usize> {
+        part_1_with_bounds(bytes, 71, 71, 1024)
+    }
+
+    fn part_2(bytes: &Bytes) -> PuzzleResult<String> {
+        part_2_with_bounds(bytes, 71, 71, 1024)
+    }
+}
+
+fn part_1_with_bounds(
+    bytes: &[Point],
+    width: usize,
+    height: usize,
+    num_bytes: usize,
+) -> PuzzleResult<usize> {
+    let bytes = &bytes[..num_bytes.min(bytes.len())];
+    check_bytes(bytes, width, height)?;
+
+    let memory_region = build_memory_region(bytes, width, height);
+
+    find_shortest_path(
+        &memory_region,
+        &Point::new(0, 0),
+        &Point::new(height - 1, width - 1),
+    )
+}
+
+type MemoryRegion = Grid<char>;
+
+fn check_bytes(bytes: &[Point], width: usize, height: usize) -> PuzzleResult<()> {
+    match bytes
+        .iter()
+        .find(|byte| byte.row >= height || byte.col >= width)
+    {
+        Some(byte) => Err(PuzzleError::parse(format!(
+            "byte {},{} is outside of the memory region",
+            byte.x(),
+            byte.y()
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn build_memory_region(bytes: &[Point], width: usize, height: usize) -> MemoryRegion {
+    let mut memory_region = Grid::new(width, height, '.');
+
+    for byte in bytes {
+        memory_region[*byte] = '#';
+    }
+
+    memory_region
+}
+
+fn find_shortest_path(
+    memory_region: &MemoryRegion,
+    start: &Point,
+    end: &Point,
+) -> PuzzleResult<usize> {
+    let path = find_path(memory_region, start, end)
+        .ok_or(PuzzleError::solve("did not find a path to the exit"))?;
+
+    Ok(path.cost)
+}
+
+fn find_path(memory_region: &MemoryRegion, start: &Point, end: &Point) -> Option<Path<Point>> {
+    search::bfs(
+        *start,
+        |point| memory_region.neighbors_where(*point, Neighbors::four, |c| *c != '#'),
+        |point| point == end,
+    )
+}
+
+fn part_2_with_bounds(
+    bytes: &[Point],
+    width: usize,
+    height: usize,
+    prefill_byte_count: usize,
+) -> PuzzleResult<String> {
+    let start = Point::new(0, 0);
+    let end = Point::new(height - 1, width - 1);
+
+    check_bytes(bytes, width, height)?;
+
+    let prefill_byte_count = prefill_byte_count.min(bytes.len());
+    let mut memory_region = build_memory_region(&bytes[..prefill_byte_count], width, height);
+
+    let mut byte_idx = prefill_byte_count;
+    while let Some(path) = find_path(&memory_region, &start, &end) {
+        let path_points = HashSet::<Point>::from_iter(path.states);
+
+        let blocking_idx = (byte_idx..bytes.len()).find(|&i| {
+            memory_region[bytes[i]] = '#';
+
+            path_points.contains(&bytes[i])
+        });
+
+        byte_idx = blocking_idx.ok_or(PuzzleError::solve("no byte cuts off the exit"))?;
+    }
+
+    let byte = bytes
+        .get(byte_idx)
+        .ok_or(PuzzleError::solve("the exit is already cut off"))?;
+    Ok(format!("{},{}", byte.x(), byte.y()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day18::YEAR, Day18::DAY, "example.txt").unwrap();
+        let input = Day18::parse(&contents).unwrap();
+
+        assert_eq!(part_1_with_bounds(&input, 7, 7, 12).unwrap(), 22);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day18::YEAR, Day18::DAY, "input.txt").unwrap();
+        let input = Day18::parse(&contents).unwrap();
+
+        assert_eq!(Day18::part_1(&input).unwrap(), 340);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day18::YEAR, Day18::DAY, "example.txt").unwrap();
+        let input = Day18::parse(&contents).unwrap();
+
+        assert_eq!(part_2_with_bounds(&input, 7, 7, 0).unwrap(), "6,1");
+    }
+
+    #[test]
+    fn test_exit_never_cut_off() {
+        let input = Day18::parse("5,4\n4,2\n").unwrap();
+
+        assert!(part_2_with_bounds(&input, 7, 7, 0).is_err());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day18::YEAR, Day18::DAY, "input.txt").unwrap();
+        let input = Day18::parse(&contents).unwrap();
+
+        assert_eq!(Day18::part_2(&input).unwrap(), "34,32");
+    }
+}
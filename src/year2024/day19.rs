@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+type Towels = HashMap<char, Vec<String>>;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 19;
+
+    type Input = (Towels, Vec<String>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<(Towels, Vec<String>)> {
+        let mut iter = contents.lines();
+
+        let mut towels: Towels = HashMap::new();
+
+        let towel_line = iter
+            .next()
+            .ok_or(PuzzleError::parse("missing the list of towel patterns"))?;
+
+        for towel in towel_line.split(',').map(|s| s.trim()) {
+            let Some(c) = towel.chars().next() else {
+                return Err(PuzzleError::parse("empty towel pattern").at(1, 1));
+            };
+            let towel = towel.to_string();
+            towels
+                .entry(c)
+                .and_modify(|v| {
+                    v.push(towel.clone());
+                })
+                .or_insert(vec![towel]);
+        }
+
+        let patterns = iter.filter(|s| !s.is_empty()).map(|s| s.into()).collect();
+
+        Ok((towels, patterns))
+    }
+
+    fn part_1((towels, patterns): &(Towels, Vec<String>)) -> PuzzleResult<usize> {
+        Ok(patterns
+            .iter()
+            .filter(|pattern| check_towel_pattern(pattern, towels))
+            .count())
+    }
+
+    fn part_2((towels, patterns): &(Towels, Vec<String>)) -> PuzzleResult<usize> {
+        Ok(patterns
+            .iter()
+            .map(|pattern| check_towel_pattern_v2(pattern, towels))
+            .sum())
+    }
+}
+
+fn check_towel_pattern(pattern: &str, towels: &Towels) -> bool {
+    check_towel_helper(pattern, towels)
+}
+
+fn check_towel_helper(pattern: &str, towels: &Towels) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+
+    let first_char = pattern.chars().next().unwrap();
+
+    let Some(possible_patterns) = towels.get(&first_char) else {
+        return false;
+    };
+
+    for possible_pattern in possible_patterns {
+        if pattern.starts_with(possible_pattern)
+            && check_towel_helper(&pattern[possible_pattern.len()..], towels)
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn check_towel_pattern_v2<'a>(pattern: &'a str, towels: &Towels) -> usize {
+    let mut memo_table: HashMap<&'a str, usize> = HashMap::new();
+
+    check_towel_helper_v2(pattern, towels, &mut memo_table)
+}
+
+fn check_towel_helper_v2<'a>(
+    pattern: &'a str,
+    towels: &Towels,
+    memo_table: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if pattern.is_empty() {
+        return 1;
+    }
+
+    if let Some(count) = memo_table.get(pattern) {
+        return *count;
+    }
+
+    let first_char = pattern.chars().next().unwrap();
+
+    let Some(possible_patterns) = towels.get(&first_char) else {
+        return 0;
+    };
+
+    let mut count = 0;
+    for possible_pattern in possible_patterns {
+        if pattern.starts_with(possible_pattern) {
+            count += check_towel_helper_v2(&pattern[possible_pattern.len()..], towels, memo_table);
+        }
+    }
+
+    memo_table.insert(pattern, count);
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day19::YEAR, Day19::DAY, "example.txt").unwrap();
+        let input = Day19::parse(&contents).unwrap();
+
+        assert_eq!(Day19::part_1(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day19::YEAR, Day19::DAY, "input.txt").unwrap();
+        let input = Day19::parse(&contents).unwrap();
+
+        assert_eq!(Day19::part_1(&input).unwrap(), 228);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day19::YEAR, Day19::DAY, "example.txt").unwrap();
+        let input = Day19::parse(&contents).unwrap();
+
+        assert_eq!(Day19::part_2(&input).unwrap(), 16);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day19::YEAR, Day19::DAY, "input.txt").unwrap();
+        let input = Day19::parse(&contents).unwrap();
+
+        assert_eq!(Day19::part_2(&input).unwrap(), 584553405070389);
+    }
+}
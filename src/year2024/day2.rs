@@ -0,0 +1,110 @@
+use utilities::{PuzzleResult, Solution};
+
+type Reports = Vec<Vec<usize>>;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 2;
+
+    type Input = Reports;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Reports> {
+        utilities::lines(contents)
+            .map(|line| {
+                line.text
+                    .split_ascii_whitespace()
+                    .map(|str| line.parse::<usize>(str))
+                    .collect::<PuzzleResult<Vec<usize>>>()
+            })
+            .collect()
+    }
+
+    fn part_1(reports: &Reports) -> PuzzleResult<usize> {
+        Ok(reports.iter().filter(|line| check_line(line)).count())
+    }
+
+    fn part_2(reports: &Reports) -> PuzzleResult<usize> {
+        Ok(reports.iter().filter(|line| check_line_v2(line)).count())
+    }
+}
+
+fn check_line(line: &[usize]) -> bool {
+    let mut increasing = None;
+
+    for i in 1..line.len() {
+        let delta = line[i].abs_diff(line[i - 1]);
+
+        if !(1..=3).contains(&delta) {
+            return false;
+        }
+
+        if increasing.is_none() {
+            increasing = Some(line[i - 1] < line[i]);
+        }
+
+        if (line[i - 1] < line[i]) != increasing.unwrap() {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn check_line_v2(line: &[usize]) -> bool {
+    if check_line(line) {
+        return true;
+    }
+
+    for skipped_index in 0..line.len() {
+        let mut line2 = line.to_owned();
+
+        line2.remove(skipped_index);
+
+        if check_line(&line2) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day2::YEAR, Day2::DAY, "example.txt").unwrap();
+        let input = Day2::parse(&contents).unwrap();
+
+        assert_eq!(Day2::part_1(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day2::YEAR, Day2::DAY, "input.txt").unwrap();
+        let input = Day2::parse(&contents).unwrap();
+
+        assert_eq!(Day2::part_1(&input).unwrap(), 670);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day2::YEAR, Day2::DAY, "example.txt").unwrap();
+        let input = Day2::parse(&contents).unwrap();
+
+        assert_eq!(Day2::part_2(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day2::YEAR, Day2::DAY, "input.txt").unwrap();
+        let input = Day2::parse(&contents).unwrap();
+
+        assert_eq!(Day2::part_2(&input).unwrap(), 700);
+    }
+}
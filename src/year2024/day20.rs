@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use utilities::grid::Grid;
+use utilities::neighbors::Neighbors;
+use utilities::point::Point;
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 20;
+
+    type Input = (Grid<char>, Point, Point);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<(Grid<char>, Point, Point)> {
+        let grid = Grid::parse(contents, Ok)?;
+
+        let start = grid
+            .find(&'S')
+            .ok_or(PuzzleError::parse("no start position 'S' found"))?;
+        let end = grid
+            .find(&'E')
+            .ok_or(PuzzleError::parse("no end position 'E' found"))?;
+
+        Ok((grid, start, end))
+    }
+
+    fn part_1((grid, start, end): &(Grid<char>, Point, Point)) -> PuzzleResult<usize> {
+        let distance_table = build_distance_table(grid, start, end)?;
+
+        Ok(count_cheats(
+            grid,
+            &distance_table,
+            get_cheat_endpoints,
+            100,
+        ))
+    }
+
+    fn part_2((grid, start, end): &(Grid<char>, Point, Point)) -> PuzzleResult<usize> {
+        let distance_table = build_distance_table(grid, start, end)?;
+
+        Ok(count_cheats(
+            grid,
+            &distance_table,
+            get_cheat_endpoints_v2,
+            100,
+        ))
+    }
+}
+
+fn build_distance_table(grid: &Grid<char>, start: &Point, end: &Point) -> PuzzleResult<Vec<Point>> {
+    let mut distance_table = vec![];
+
+    let mut current = Some(*end);
+    let mut last = None;
+
+    while let Some(point) = current {
+        distance_table.push(point);
+
+        if point == *start {
+            break;
+        }
+
+        let next = grid
+            .neighbors_where(point, Neighbors::four, |c| *c != '#')
+            .find(|neighbor| last.is_none_or(|p| *neighbor != p))
+            .ok_or(PuzzleError::solve("the track reaches a dead end"))?;
+
+        last = current;
+        current = Some(next);
+    }
+
+    Ok(distance_table)
+}
+
+fn count_cheats<F>(
+    grid: &Grid<char>,
+    distance_table: &[Point],
+    get_endpoints: F,
+    threshold: usize,
+) -> usize
+where
+    F: Fn(&Point, &Grid<char>) -> Vec<Point>,
+{
+    let reverse_distance_table: HashMap<Point, usize> = HashMap::from_iter(
+        distance_table
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| (*point, idx)),
+    );
+
+    let mut cheats: HashMap<usize, Vec<Point>> = HashMap::new();
+
+    for point in distance_table.iter().rev() {
+        let endpoints = get_endpoints(point, grid);
+
+        for end in endpoints {
+            if let Some(speedup) =
+                calculate_cheat_speedup(&reverse_distance_table, point, &end)
+            {
+                cheats
+                    .entry(speedup)
+                    .and_modify(|v| v.push(end))
+                    .or_insert(vec![end]);
+            }
+        }
+    }
+
+    cheats
+        .iter()
+        .filter(|(&k, _)| k >= threshold)
+        .map(|(_, v)| v.len())
+        .sum()
+}
+
+fn get_cheat_endpoints(start: &Point, grid: &Grid<char>) -> Vec<Point> {
+    let mut end_points = vec![];
+
+    if start.row >= 2
+        && grid[Point::new(start.row - 1, start.col)] == '#'
+        && grid[Point::new(start.row - 2, start.col)] != '#'
+    {
+        end_points.push(Point::new(start.row - 2, start.col));
+    }
+
+    if start.row + 2 < grid.height()
+        && grid[Point::new(start.row + 1, start.col)] == '#'
+        && grid[Point::new(start.row + 2, start.col)] != '#'
+    {
+        end_points.push(Point::new(start.row + 2, start.col));
+    }
+
+    if start.col >= 2
+        && grid[Point::new(start.row, start.col - 1)] == '#'
+        && grid[Point::new(start.row, start.col - 2)] != '#'
+    {
+        end_points.push(Point::new(start.row, start.col - 2));
+    }
+
+    if start.col + 2 < grid.width()
+        && grid[Point::new(start.row, start.col + 1)] == '#'
+        && grid[Point::new(start.row, start.col + 2)] != '#'
+    {
+        end_points.push(Point::new(start.row, start.col + 2));
+    }
+
+    end_points
+}
+
+fn calculate_cheat_speedup(
+    reverse_distance_table: &HashMap<Point, usize>,
+    start: &Point,
+    end: &Point,
+) -> Option<usize> {
+    // Open cells off the track can't be cheated to.
+    let start_score = *reverse_distance_table.get(start)?;
+    let end_score = *reverse_distance_table.get(end)?;
+
+    let cheat_distance = start.manhattan(end);
+
+    if start_score > (end_score + cheat_distance) {
+        Some(start_score - end_score - cheat_distance)
+    } else {
+        None
+    }
+}
+
+fn get_cheat_endpoints_v2(start: &Point, grid: &Grid<char>) -> Vec<Point> {
+    let mut endpoints = vec![];
+
+    let max_cheat_length = 20;
+    let radius = max_cheat_length + 1;
+
+    let min_row = start.row.saturating_sub(radius);
+    let max_row = (start.row + radius).min(grid.height());
+
+    let min_col = start.col.saturating_sub(radius);
+    let max_col = (start.col + radius).min(grid.width());
+
+    for r in min_row..max_row {
+        for c in min_col..max_col {
+            let point = Point::new(r, c);
+
+            if start.manhattan(&point) > max_cheat_length {
+                continue;
+            }
+
+            if grid[point] == '#' {
+                continue;
+            }
+
+            if point == *start {
+                continue;
+            }
+
+            endpoints.push(point);
+        }
+    }
+
+    endpoints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let contents = utilities::read_file_data(Day20::YEAR, Day20::DAY, "example.txt").unwrap();
+        let (grid, start, end) = Day20::parse(&contents).unwrap();
+        let distance_table = build_distance_table(&grid, &start, &end).unwrap();
+
+        assert_eq!(
+            count_cheats(&grid, &distance_table, get_cheat_endpoints, 20),
+            5
+        );
+        assert_eq!(
+            count_cheats(&grid, &distance_table, get_cheat_endpoints_v2, 74),
+            7
+        );
+    }
+
+    #[test]
+    fn test_small_grid_with_open_cell_off_track() {
+        let input = Day20::parse("#######\n#S...E#\n#######\n#.#####\n#######\n").unwrap();
+
+        assert_eq!(Day20::part_1(&input).unwrap(), 0);
+        assert_eq!(Day20::part_2(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day20::YEAR, Day20::DAY, "input.txt").unwrap();
+        let input = Day20::parse(&contents).unwrap();
+
+        assert_eq!(Day20::part_1(&input).unwrap(), 1381);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day20::YEAR, Day20::DAY, "input.txt").unwrap();
+        let input = Day20::parse(&contents).unwrap();
+
+        assert_eq!(Day20::part_2(&input).unwrap(), 982124);
+    }
+}
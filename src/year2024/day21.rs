@@ -1,32 +1,63 @@
 use std::collections::HashMap;
 
-use crate::day6::Point;
+use utilities::direction::Direction;
+use utilities::point::{Point, Vector};
+use utilities::{PuzzleResult, Solution};
 
-const DAY: usize = 21;
+type Codes = Vec<Vec<char>>;
 
-pub fn run() {
-    utilities::run_puzzle(DAY, part_1, part_2);
-}
+type Keypad = HashMap<char, Point>;
 
-fn part_1(contents: &str) -> usize {
-    let codes = parse_input(contents);
+pub struct Day21;
 
-    let (numeric_keypad, robot_keypad) = build_keypad_maps();
+impl Solution for Day21 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 21;
 
-    codes
-        .iter()
-        .map(|code| {
-            let input_length = find_shortest_input(code, &numeric_keypad, &robot_keypad, 3);
-            calculate_complexity_code(code, input_length)
-        })
-        .sum()
-}
+    type Input = Codes;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn parse_input(contents: &str) -> Vec<Vec<char>> {
-    contents.lines().map(|s| s.chars().collect()).collect()
-}
+    fn parse(contents: &str) -> PuzzleResult<Codes> {
+        utilities::lines(contents)
+            .map(|line| {
+                if let Some(idx) = line.text.find(|c: char| !c.is_ascii_digit() && c != 'A') {
+                    return Err(line.error_at(&line.text[idx..], "codes can only use 0-9 and A"));
+                }
 
-type Keypad = HashMap<char, Point>;
+                if !line.text.contains(|c: char| c.is_ascii_digit()) {
+                    return Err(line.error("codes need a numeric part"));
+                }
+
+                Ok(line.text.chars().collect())
+            })
+            .collect()
+    }
+
+    fn part_1(codes: &Codes) -> PuzzleResult<usize> {
+        let (numeric_keypad, robot_keypad) = build_keypad_maps();
+
+        Ok(codes
+            .iter()
+            .map(|code| {
+                let input_length = find_shortest_input(code, &numeric_keypad, &robot_keypad, 3);
+                calculate_complexity_code(code, input_length)
+            })
+            .sum())
+    }
+
+    fn part_2(codes: &Codes) -> PuzzleResult<usize> {
+        let (numeric_keypad, robot_keypad) = build_keypad_maps();
+
+        Ok(codes
+            .iter()
+            .map(|code| {
+                let input_length = find_shortest_input_v2(code, &numeric_keypad, &robot_keypad, 26);
+                calculate_complexity_code(code, input_length)
+            })
+            .sum())
+    }
+}
 
 fn build_keypad_maps() -> (Keypad, Keypad) {
     let numeric_keypad = build_button_map("789\n456\n123\n_0A");
@@ -52,10 +83,10 @@ fn find_shortest_input(
     robot_keypad: &Keypad,
     num_robots: usize,
 ) -> usize {
-    let mut path = process_keypad(code, &numeric_keypad);
+    let mut path = process_keypad(code, numeric_keypad);
 
     for _ in 0..(num_robots - 1) {
-        path = process_keypad(&path, &robot_keypad);
+        path = process_keypad(&path, robot_keypad);
     }
 
     path.len()
@@ -68,7 +99,7 @@ fn process_keypad(input_path: &[char], keypad: &Keypad) -> Vec<char> {
         let first_key = if i == 0 { 'A' } else { input_path[i - 1] };
         let second_key = input_path[i];
 
-        let path = find_path(first_key, second_key, &keypad);
+        let path = find_path(first_key, second_key, keypad);
 
         new_path.extend(path.iter());
         new_path.push('A');
@@ -82,7 +113,8 @@ fn find_path(start: char, end: char, keypad: &Keypad) -> Vec<char> {
     let end_point = keypad[&end];
     let dead_spot = keypad[&'_'];
 
-    let (delta_v, delta_h) = manhattan_distance(&start_point, &end_point);
+    let delta = end_point - start_point;
+    let (delta_v, delta_h) = (delta.row, delta.col);
 
     let mut path = vec![];
 
@@ -95,11 +127,8 @@ fn find_path(start: char, end: char, keypad: &Keypad) -> Vec<char> {
     }
 
     // Moving two directions, check if the dead spot is one of the corners.
-    let vert_corner_row = (start_point.row as isize + delta_v) as usize;
-    let horz_corner_col = (start_point.col as isize + delta_h) as usize;
-
-    let vert_corner_dead = Point::new(vert_corner_row, start_point.col) == dead_spot;
-    let horz_corner_dead = Point::new(start_point.row, horz_corner_col) == dead_spot;
+    let vert_corner_dead = start_point + Vector::new(delta_v, 0) == Some(dead_spot);
+    let horz_corner_dead = start_point + Vector::new(0, delta_h) == Some(dead_spot);
 
     if horz_corner_dead {
         // go vertical first
@@ -138,25 +167,18 @@ fn find_path(start: char, end: char, keypad: &Keypad) -> Vec<char> {
 }
 
 fn fill_path(current_path: &mut Vec<char>, amount: isize, is_horizontal: bool) {
-    let c = match (amount > 0, is_horizontal) {
-        (true, true) => '>',
-        (false, true) => '<',
-        (true, false) => 'v',
-        (false, false) => '^',
+    let direction = match (amount > 0, is_horizontal) {
+        (true, true) => Direction::East,
+        (false, true) => Direction::West,
+        (true, false) => Direction::South,
+        (false, false) => Direction::North,
     };
 
     for _ in 0..amount.abs() {
-        current_path.push(c);
+        current_path.push(direction.arrow());
     }
 }
 
-fn manhattan_distance(start: &Point, end: &Point) -> (isize, isize) {
-    let vertical = end.row as isize - start.row as isize;
-    let horizontal = end.col as isize - start.col as isize;
-
-    (vertical, horizontal)
-}
-
 fn calculate_complexity_code(code: &[char], input_length: usize) -> usize {
     let str_value = String::from_iter(code.iter().filter(|c| c.is_numeric()));
     let numeric_value = str_value.parse::<usize>().unwrap();
@@ -164,20 +186,6 @@ fn calculate_complexity_code(code: &[char], input_length: usize) -> usize {
     numeric_value * input_length
 }
 
-fn part_2(contents: &str) -> usize {
-    let codes = parse_input(contents);
-
-    let (numeric_keypad, robot_keypad) = build_keypad_maps();
-
-    codes
-        .iter()
-        .map(|code| {
-            let input_length = find_shortest_input_v2(code, &numeric_keypad, &robot_keypad, 26);
-            calculate_complexity_code(code, input_length)
-        })
-        .sum()
-}
-
 fn find_shortest_input_v2(
     code: &[char],
     numeric_keypad: &Keypad,
@@ -186,7 +194,7 @@ fn find_shortest_input_v2(
 ) -> usize {
     let mut path_map = HashMap::new();
 
-    let path = process_keypad_v2(&code.iter().collect::<String>(), &numeric_keypad);
+    let path = process_keypad_v2(&code.iter().collect::<String>(), numeric_keypad);
 
     for path_piece in path {
         update_map(&mut path_map, path_piece, 1);
@@ -196,7 +204,7 @@ fn find_shortest_input_v2(
         let mut new_path_map = HashMap::new();
 
         for (path_piece, count) in path_map {
-            let new_path = process_keypad_v2(&path_piece, &robot_keypad);
+            let new_path = process_keypad_v2(&path_piece, robot_keypad);
 
             for new_path_piece in new_path {
                 update_map(&mut new_path_map, new_path_piece, count);
@@ -212,7 +220,7 @@ fn find_shortest_input_v2(
         .sum()
 }
 
-fn process_keypad_v2(input_path: &String, keypad: &Keypad) -> Vec<String> {
+fn process_keypad_v2(input_path: &str, keypad: &Keypad) -> Vec<String> {
     let mut new_path = vec![];
 
     let actions = input_path.chars().collect::<Vec<char>>();
@@ -221,7 +229,7 @@ fn process_keypad_v2(input_path: &String, keypad: &Keypad) -> Vec<String> {
         let first_key = if i == 0 { 'A' } else { actions[i - 1] };
         let second_key = actions[i];
 
-        let mut path = find_path(first_key, second_key, &keypad);
+        let mut path = find_path(first_key, second_key, keypad);
 
         path.push('A');
 
@@ -243,22 +251,25 @@ mod tests {
 
     #[test]
     fn test_example_part_1() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day21::YEAR, Day21::DAY, "example.txt").unwrap();
+        let input = Day21::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 126384);
+        assert_eq!(Day21::part_1(&input).unwrap(), 126384);
     }
 
     #[test]
     fn test_input_part_1() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day21::YEAR, Day21::DAY, "input.txt").unwrap();
+        let input = Day21::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 270084);
+        assert_eq!(Day21::part_1(&input).unwrap(), 270084);
     }
 
     #[test]
     fn test_input_part_2() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day21::YEAR, Day21::DAY, "input.txt").unwrap();
+        let input = Day21::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 329431019997766);
+        assert_eq!(Day21::part_2(&input).unwrap(), 329431019997766);
     }
 }
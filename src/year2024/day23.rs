@@ -1,45 +1,61 @@
 use std::collections::{HashMap, HashSet};
 
-const DAY: usize = 23;
+use utilities::{PuzzleError, PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(DAY, part_1, part_2);
-}
+type Connections = Vec<(String, String)>;
 
-fn part_1(contents: &str) -> usize {
-    let connections = parse_input(contents);
-    let graph = build_graph(&connections);
-    let triples = find_triples(&graph);
+pub struct Day23;
 
-    count_triples_with_t(&triples)
-}
+impl Solution for Day23 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 23;
+
+    type Input = Connections;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(contents: &str) -> PuzzleResult<Connections> {
+        utilities::lines(contents)
+            .map(|line| {
+                line.text
+                    .split_once('-')
+                    .map(|(a, b)| (a.to_string(), b.to_string()))
+                    .ok_or_else(|| line.error("expected a connection like kh-tc"))
+            })
+            .collect()
+    }
 
-fn parse_input(contents: &str) -> Vec<(&str, &str)> {
-    contents
-        .lines()
-        .map(|line| {
-            let pieces = line.split('-').collect::<Vec<_>>();
-            (pieces[0], pieces[1])
-        })
-        .collect()
+    fn part_1(connections: &Connections) -> PuzzleResult<usize> {
+        let graph = build_graph(connections);
+        let triples = find_triples(&graph);
+
+        Ok(count_triples_with_t(&triples))
+    }
+
+    fn part_2(connections: &Connections) -> PuzzleResult<String> {
+        let graph = build_graph(connections);
+        let triples = find_triples(&graph);
+
+        Ok(find_biggest_group(&graph, &triples)?.join(","))
+    }
 }
 
-fn build_graph<'x>(connections: &[(&'x str, &'x str)]) -> HashMap<&'x str, HashSet<&'x str>> {
+fn build_graph(connections: &[(String, String)]) -> HashMap<&str, HashSet<&str>> {
     let mut graph: HashMap<&str, HashSet<&str>> = HashMap::new();
 
     for (a, b) in connections {
         graph
             .entry(a)
             .and_modify(|v| {
-                v.insert(*b);
+                v.insert(b);
             })
-            .or_insert(HashSet::from_iter([*b]));
+            .or_insert(HashSet::from_iter([b.as_str()]));
         graph
             .entry(b)
             .and_modify(|v| {
-                v.insert(*a);
+                v.insert(a);
             })
-            .or_insert(HashSet::from_iter([*a]));
+            .or_insert(HashSet::from_iter([a.as_str()]));
     }
 
     graph
@@ -78,18 +94,10 @@ fn count_triples_with_t(triples: &HashSet<Vec<&str>>) -> usize {
         .count()
 }
 
-fn part_2(contents: &str) -> String {
-    let connections = parse_input(contents);
-    let graph = build_graph(&connections);
-    let triples = find_triples(&graph);
-
-    find_biggest_group(&graph, &triples).join(",")
-}
-
 fn find_biggest_group<'x>(
     graph: &HashMap<&'x str, HashSet<&'x str>>,
     triples: &HashSet<Vec<&'x str>>,
-) -> Vec<&'x str> {
+) -> PuzzleResult<Vec<&'x str>> {
     let groups: Vec<HashSet<&str>> = triples
         .iter()
         .map(|triple| HashSet::from_iter(triple.iter().cloned()))
@@ -99,11 +107,11 @@ fn find_biggest_group<'x>(
         .iter()
         .map(|group| expand_group(graph, group))
         .max_by(|a, b| a.len().cmp(&b.len()))
-        .unwrap();
+        .ok_or(PuzzleError::solve("no groups of three connected computers"))?;
 
     group.sort();
 
-    group
+    Ok(group)
 }
 
 fn expand_group<'x>(
@@ -134,29 +142,36 @@ mod tests {
 
     #[test]
     fn test_example_part_1() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day23::YEAR, Day23::DAY, "example.txt").unwrap();
+        let input = Day23::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 7);
+        assert_eq!(Day23::part_1(&input).unwrap(), 7);
     }
 
     #[test]
     fn test_input_part_1() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day23::YEAR, Day23::DAY, "input.txt").unwrap();
+        let input = Day23::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 1200);
+        assert_eq!(Day23::part_1(&input).unwrap(), 1200);
     }
 
     #[test]
     fn test_example_part_2() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day23::YEAR, Day23::DAY, "example.txt").unwrap();
+        let input = Day23::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), "co,de,ka,ta");
+        assert_eq!(Day23::part_2(&input).unwrap(), "co,de,ka,ta");
     }
 
     #[test]
     fn test_input_part_2() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day23::YEAR, Day23::DAY, "input.txt").unwrap();
+        let input = Day23::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), "ag,gh,hh,iv,jx,nq,oc,qm,rb,sm,vm,wu,zr");
+        assert_eq!(
+            Day23::part_2(&input).unwrap(),
+            "ag,gh,hh,iv,jx,nq,oc,qm,rb,sm,vm,wu,zr"
+        );
     }
 }
@@ -0,0 +1,225 @@
+use std::collections::{HashMap, VecDeque};
+
+use utilities::{PuzzleResult, Solution};
+
+type GateInputs = HashMap<String, Vec<usize>>;
+type StartingValues = HashMap<String, usize>;
+type Device = (Vec<Gate>, GateInputs, StartingValues);
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 24;
+
+    type Input = Device;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Device> {
+        let mut starting_values = HashMap::new();
+        let mut gates = vec![];
+        let mut gate_inputs: GateInputs = HashMap::new();
+
+        let mut done_starting_values = false;
+
+        for line in utilities::lines(contents) {
+            if line.text.is_empty() {
+                done_starting_values = true;
+                continue;
+            }
+
+            if !done_starting_values {
+                let Some((wire, value)) = line.text.split_once(": ") else {
+                    return Err(line.error("expected a starting value like x00: 1"));
+                };
+
+                starting_values.insert(wire.to_string(), line.parse::<usize>(value)?);
+            } else {
+                let Some((gate, output)) = line.text.split_once(" -> ") else {
+                    return Err(line.error("expected a gate like x00 AND y00 -> z00"));
+                };
+                let gate_pieces = gate.split_ascii_whitespace().collect::<Vec<_>>();
+
+                let [first_input, gate_type, second_input] = gate_pieces[..] else {
+                    return Err(line.error("expected a gate like x00 AND y00 -> z00"));
+                };
+
+                let Some(gate_type) = GateType::from_str(gate_type) else {
+                    return Err(
+                        line.error_at(gate_type, format!("unknown gate type '{gate_type}'"))
+                    );
+                };
+
+                if output.starts_with('z') && output[1..].parse::<usize>().is_err() {
+                    return Err(line.error_at(output, format!("invalid output wire '{output}'")));
+                }
+
+                let idx = gates.len();
+
+                gates.push(Gate::new(output, gate_type));
+
+                gate_inputs
+                    .entry(first_input.to_string())
+                    .and_modify(|v| v.push(idx))
+                    .or_insert(vec![idx]);
+                gate_inputs
+                    .entry(second_input.to_string())
+                    .and_modify(|v| v.push(idx))
+                    .or_insert(vec![idx]);
+            }
+        }
+
+        Ok((gates, gate_inputs, starting_values))
+    }
+
+    fn part_1((gates, gate_inputs, starting_values): &Device) -> PuzzleResult<usize> {
+        Ok(run_simulation(gates.clone(), gate_inputs, starting_values))
+    }
+
+    fn part_2(_device: &Device) -> PuzzleResult<usize> {
+        Ok(0)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum GateType {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateType {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "AND" => Some(GateType::And),
+            "OR" => Some(GateType::Or),
+            "XOR" => Some(GateType::Xor),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Gate {
+    gate_type: GateType,
+    input: Option<usize>,
+    output: String,
+}
+
+impl Gate {
+    fn new(output: &str, gate_type: GateType) -> Self {
+        Self {
+            gate_type,
+            input: None,
+            output: output.to_string(),
+        }
+    }
+
+    fn apply_input(&mut self, input: usize) -> Option<usize> {
+        if let Some(prev_input) = self.input {
+            match self.gate_type {
+                GateType::And => Some(prev_input & input),
+                GateType::Or => Some(prev_input | input),
+                GateType::Xor => Some(prev_input ^ input),
+            }
+        } else {
+            self.input = Some(input);
+            None
+        }
+    }
+}
+
+fn run_simulation(
+    mut gates: Vec<Gate>,
+    gate_inputs: &GateInputs,
+    starting_values: &StartingValues,
+) -> usize {
+    let mut wire_queue = VecDeque::new();
+    let mut end_wires = HashMap::new();
+
+    // Prefill the wire queue.
+    for (wire, initial_value) in starting_values {
+        if let Some(dest_indices) = gate_inputs.get(wire) {
+            push_all(&mut wire_queue, dest_indices, *initial_value);
+        }
+    }
+
+    while let Some((dest_idx, value)) = wire_queue.pop_front() {
+        let gate = gates.get_mut(dest_idx).unwrap();
+
+        let Some(value) = gate.apply_input(value) else {
+            continue;
+        };
+
+        if gate.output.starts_with('z') {
+            let digit_position = gate.output[1..].parse::<usize>().unwrap();
+            end_wires.insert(digit_position, value);
+        } else if let Some(dest_indices) = gate_inputs.get(&gate.output) {
+            push_all(&mut wire_queue, dest_indices, value);
+        }
+    }
+
+    build_value(&end_wires)
+}
+
+fn push_all(wire_queue: &mut VecDeque<(usize, usize)>, dest_indices: &Vec<usize>, value: usize) {
+    for dest_idx in dest_indices {
+        wire_queue.push_back((*dest_idx, value));
+    }
+}
+
+fn build_value(digits: &HashMap<usize, usize>) -> usize {
+    let mut value = 0;
+
+    for (digit, digit_value) in digits {
+        value |= digit_value << digit;
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day24::YEAR, Day24::DAY, "example.txt").unwrap();
+        let input = Day24::parse(&contents).unwrap();
+
+        assert_eq!(Day24::part_1(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_example2_part_1() {
+        let contents = utilities::read_file_data(Day24::YEAR, Day24::DAY, "example2.txt").unwrap();
+        let input = Day24::parse(&contents).unwrap();
+
+        assert_eq!(Day24::part_1(&input).unwrap(), 2024);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day24::YEAR, Day24::DAY, "input.txt").unwrap();
+        let input = Day24::parse(&contents).unwrap();
+
+        assert_eq!(Day24::part_1(&input).unwrap(), 36902370467952);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day24::YEAR, Day24::DAY, "example.txt").unwrap();
+        let input = Day24::parse(&contents).unwrap();
+
+        assert_eq!(Day24::part_2(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day24::YEAR, Day24::DAY, "input.txt").unwrap();
+        let input = Day24::parse(&contents).unwrap();
+
+        assert_eq!(Day24::part_2(&input).unwrap(), 0);
+    }
+}
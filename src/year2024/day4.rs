@@ -0,0 +1,180 @@
+use utilities::direction::Direction8;
+use utilities::grid::Grid;
+use utilities::point::Point;
+use utilities::{PuzzleResult, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 4;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Grid<char>> {
+        Grid::parse(contents, Ok)
+    }
+
+    fn part_1(grid: &Grid<char>) -> PuzzleResult<usize> {
+        let row_count = count_rows(grid);
+        let col_count = count_columns(grid);
+        let diag_bl_tr_count = count_diagonals_bl_tr(grid);
+        let diag_tl_br_count = count_diagonals_tl_br(grid);
+
+        Ok(row_count + col_count + diag_bl_tr_count + diag_tl_br_count)
+    }
+
+    fn part_2(grid: &Grid<char>) -> PuzzleResult<usize> {
+        // Find an 'X' of MAS
+        let corner = |point: Point, direction: Direction8| {
+            grid.offset(point, direction.offset())
+                .map(|corner| grid[corner])
+        };
+
+        Ok(grid
+            .find_all(&'A')
+            .filter(|point| {
+                let tl = corner(*point, Direction8::NorthWest);
+                let tr = corner(*point, Direction8::NorthEast);
+                let bl = corner(*point, Direction8::SouthWest);
+                let br = corner(*point, Direction8::SouthEast);
+
+                check_pair(tl, br) && check_pair(bl, tr)
+            })
+            .count())
+    }
+}
+
+fn count_rows(grid: &Grid<char>) -> usize {
+    let rows = grid.rows().map(String::from_iter).collect::<Vec<String>>();
+
+    count_instances(&rows)
+}
+
+fn count_columns(grid: &Grid<char>) -> usize {
+    let columns = grid
+        .columns()
+        .map(String::from_iter)
+        .collect::<Vec<String>>();
+
+    count_instances(&columns)
+}
+
+fn count_diagonals_bl_tr(grid: &Grid<char>) -> usize {
+    let mut diagonals = vec![String::new(); (grid.height() + grid.width()).saturating_sub(1)];
+
+    // rows = 4, cols = 6 -> vec size = 9, indexes 0-8
+    // .012345
+    // 0ABCDEF
+    // 1BCDEFG
+    // 2CDEFGH
+    // 3DEFGHI
+    //
+    // diagonal index = row + col
+
+    for row in (0..grid.height()).rev() {
+        for col in 0..grid.width() {
+            diagonals[row + col].push(grid[Point::new(row, col)]);
+        }
+    }
+
+    count_instances(&diagonals)
+}
+
+fn count_diagonals_tl_br(grid: &Grid<char>) -> usize {
+    let mut diagonals = vec![String::new(); (grid.height() + grid.width()).saturating_sub(1)];
+
+    // rows = 4, cols = 6 -> vec size = 9, indexes 0-8
+    // .012345
+    // 0FEDCBA
+    // 1GFEDCB
+    // 2HGFEDC
+    // 3IHGFED
+    //
+    // diagonal index = num_cols - 1 - col + row
+
+    let num_cols = grid.width();
+
+    for row in (0..grid.height()).rev() {
+        for col in (0..num_cols).rev() {
+            diagonals[num_cols - 1 - col + row].push(grid[Point::new(row, col)]);
+        }
+    }
+
+    count_instances(&diagonals)
+}
+
+fn count_instances(lines: &[String]) -> usize {
+    const XMAS: &str = "XMAS";
+
+    let mut count = 0;
+
+    for line in lines.iter() {
+        let mut line_count = 0;
+
+        let mut cursor = &line[..];
+
+        while let Some(position) = cursor.find(XMAS) {
+            line_count += 1;
+            cursor = &cursor[position + XMAS.len()..];
+        }
+
+        let line = line.chars().rev().collect::<String>();
+
+        let mut cursor = &line[..];
+        while let Some(position) = cursor.find(XMAS) {
+            line_count += 1;
+            cursor = &cursor[position + XMAS.len()..];
+        }
+
+        count += line_count;
+    }
+
+    count
+}
+
+fn check_pair(first: Option<char>, second: Option<char>) -> bool {
+    matches!(
+        (first, second),
+        (Some('M'), Some('S')) | (Some('S'), Some('M'))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day4::YEAR, Day4::DAY, "example.txt").unwrap();
+        let input = Day4::parse(&contents).unwrap();
+
+        assert_eq!(Day4::part_1(&input).unwrap(), 18);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day4::YEAR, Day4::DAY, "input.txt").unwrap();
+        let input = Day4::parse(&contents).unwrap();
+
+        assert_eq!(Day4::part_1(&input).unwrap(), 2551);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day4::YEAR, Day4::DAY, "example.txt").unwrap();
+        let input = Day4::parse(&contents).unwrap();
+
+        assert_eq!(Day4::part_2(&input).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day4::YEAR, Day4::DAY, "input.txt").unwrap();
+        let input = Day4::parse(&contents).unwrap();
+
+        assert_eq!(Day4::part_2(&input).unwrap(), 1985);
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+
+use utilities::{PuzzleResult, Solution};
+
+type Mapping = HashMap<usize, HashSet<usize>>;
+
+type PrintQueue = (Mapping, Vec<Vec<usize>>);
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 5;
+
+    type Input = PrintQueue;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<PrintQueue> {
+        let mut mapping: Mapping = HashMap::new();
+        let mut updates = vec![];
+
+        let mut done_mapping = false;
+
+        for line in utilities::lines(contents) {
+            if line.text.is_empty() {
+                done_mapping = true;
+
+                continue;
+            }
+
+            if !done_mapping {
+                // Parsing the mapping section.
+                let pages = line
+                    .text
+                    .split('|')
+                    .map(|s| line.parse::<usize>(s))
+                    .collect::<PuzzleResult<Vec<usize>>>()?;
+
+                let [before, after] = pages[..] else {
+                    return Err(line.error("expected an ordering rule like 47|53"));
+                };
+
+                mapping
+                    .entry(before)
+                    .and_modify(|val| {
+                        val.insert(after);
+                    })
+                    .or_insert({
+                        let mut val = HashSet::new();
+                        val.insert(after);
+                        val
+                    });
+            } else {
+                // Parsing the updates.
+                let pages = line
+                    .text
+                    .split(',')
+                    .map(|s| line.parse::<usize>(s))
+                    .collect::<PuzzleResult<Vec<usize>>>()?;
+
+                updates.push(pages);
+            }
+        }
+
+        Ok((mapping, updates))
+    }
+
+    fn part_1((mapping, updates): &PrintQueue) -> PuzzleResult<usize> {
+        let mut middle_sum = 0;
+
+        for update in updates {
+            middle_sum += validate_updates(update, mapping).unwrap_or(0);
+        }
+
+        Ok(middle_sum)
+    }
+
+    fn part_2((mapping, updates): &PrintQueue) -> PuzzleResult<usize> {
+        let mut middle_sum = 0;
+
+        for update in updates {
+            if validate_updates(update, mapping).is_some() {
+                continue;
+            }
+
+            // Maybe I can do it linearly? validate_updates fails because the intersection of mapping[item] and pages
+            // already seen is not empty. In theory, if a page fails, it can be moved just before the "earliest" page
+            // in the intersection.
+
+            middle_sum += validate_updates_v2(update, mapping).unwrap_or(0);
+        }
+
+        Ok(middle_sum)
+    }
+}
+
+fn validate_updates(update: &Vec<usize>, mapping: &Mapping) -> Option<usize> {
+    let mut seen_pages = HashSet::new();
+
+    for page in update {
+        // Just need to check that this page is after all pages I have already seen.
+        if let Some(pages_after) = mapping.get(page) {
+            if seen_pages.intersection(pages_after).count() != 0 {
+                return None;
+            }
+        }
+
+        seen_pages.insert(*page);
+    }
+
+    Some(update[update.len() / 2])
+}
+
+fn validate_updates_v2(update: &Vec<usize>, mapping: &Mapping) -> Option<usize> {
+    let mut seen_pages = HashSet::new();
+
+    let mut adjusted = vec![];
+
+    for page in update {
+        if let Some(pages_after) = mapping.get(page) {
+            let intersection = seen_pages.intersection(pages_after).collect::<HashSet<_>>();
+
+            if intersection.is_empty() {
+                adjusted.push(*page);
+            } else if let Some(index) = adjusted.iter().position(|item| intersection.contains(item))
+            {
+                adjusted.insert(index, *page);
+            }
+        } else {
+            adjusted.push(*page);
+        }
+
+        seen_pages.insert(*page);
+    }
+
+    Some(adjusted[adjusted.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day5::YEAR, Day5::DAY, "example.txt").unwrap();
+        let input = Day5::parse(&contents).unwrap();
+
+        assert_eq!(Day5::part_1(&input).unwrap(), 143);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day5::YEAR, Day5::DAY, "input.txt").unwrap();
+        let input = Day5::parse(&contents).unwrap();
+
+        assert_eq!(Day5::part_1(&input).unwrap(), 7307);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day5::YEAR, Day5::DAY, "example.txt").unwrap();
+        let input = Day5::parse(&contents).unwrap();
+
+        assert_eq!(Day5::part_2(&input).unwrap(), 123);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day5::YEAR, Day5::DAY, "input.txt").unwrap();
+        let input = Day5::parse(&contents).unwrap();
+
+        assert_eq!(Day5::part_2(&input).unwrap(), 4713);
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use utilities::direction::Direction;
+use utilities::grid::Grid;
+use utilities::point::Point;
+use utilities::{PuzzleError, PuzzleResult, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 6;
+
+    type Input = (Grid<char>, Point);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<(Grid<char>, Point)> {
+        let grid = Grid::parse(contents, Ok)?;
+
+        let starting_position = grid
+            .find(&'^')
+            .ok_or(PuzzleError::parse("no guard starting position '^' found"))?;
+
+        Ok((grid, starting_position))
+    }
+
+    fn part_1((grid, starting_position): &(Grid<char>, Point)) -> PuzzleResult<usize> {
+        let visited = walk_path(*starting_position, grid);
+
+        Ok(visited.len())
+    }
+
+    fn part_2((grid, starting_position): &(Grid<char>, Point)) -> PuzzleResult<usize> {
+        let mut grid = grid.clone();
+
+        Ok(walk_path_v2(*starting_position, &mut grid))
+    }
+}
+
+fn get_next_position(
+    current: &Point,
+    facing: Direction,
+    grid: &Grid<char>,
+) -> Option<(Point, Direction)> {
+    let next_position = grid.offset(*current, facing.offset())?;
+
+    if grid[next_position] == '#' {
+        // Obstacle, need to turn.
+        Some((*current, facing.turn_right()))
+    } else {
+        Some((next_position, facing))
+    }
+}
+
+fn walk_path(starting_position: Point, grid: &Grid<char>) -> HashSet<Point> {
+    let mut visited = HashSet::new();
+    visited.insert(starting_position);
+
+    let mut current = starting_position;
+    let mut facing = Direction::North;
+
+    while let Some((next_position, next_facing)) = get_next_position(&current, facing, grid) {
+        visited.insert(next_position);
+
+        current = next_position;
+        facing = next_facing;
+    }
+
+    visited
+}
+
+fn walk_path_v2(starting_position: Point, grid: &mut Grid<char>) -> usize {
+    let mut current = starting_position;
+    let mut facing = Direction::North;
+
+    let mut visited = HashSet::new();
+
+    let mut count = 0;
+
+    while let Some((next_position, next_facing)) = get_next_position(&current, facing, grid) {
+        // If the facing is the same way, pretend first we had a block there.
+        if next_facing == facing && !visited.contains(&next_position) {
+            grid[next_position] = '#';
+
+            if single_walk(current, facing, grid) {
+                count += 1;
+            }
+
+            grid[next_position] = '.';
+        }
+
+        visited.insert(next_position);
+
+        current = next_position;
+        facing = next_facing;
+    }
+
+    count
+}
+
+fn single_walk(starting_position: Point, starting_facing: Direction, grid: &Grid<char>) -> bool {
+    // Use the tortoise and hare cycle detection algorithm.
+    let mut current_hare = starting_position;
+    let mut facing_hare = starting_facing;
+
+    let mut current_tortoise = starting_position;
+    let mut facing_tortoise = starting_facing;
+
+    loop {
+        let Some((next_position_hare, next_facing_hare)) =
+            get_next_position(&current_hare, facing_hare, grid)
+        else {
+            return false;
+        };
+
+        current_hare = next_position_hare;
+        facing_hare = next_facing_hare;
+
+        let Some((next_position_hare, next_facing_hare)) =
+            get_next_position(&current_hare, facing_hare, grid)
+        else {
+            return false;
+        };
+
+        current_hare = next_position_hare;
+        facing_hare = next_facing_hare;
+
+        // The tortoise can be advanced as well, and it should always be valid (since the hare already went past this point).
+        let Some((next_position_tortoise, next_facing_tortoise)) =
+            get_next_position(&current_tortoise, facing_tortoise, grid)
+        else {
+            panic!("Tortoise shouldn't have fallen off the grid!");
+        };
+
+        current_tortoise = next_position_tortoise;
+        facing_tortoise = next_facing_tortoise;
+
+        if current_hare == current_tortoise && facing_hare == facing_tortoise {
+            return true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day6::YEAR, Day6::DAY, "example.txt").unwrap();
+        let input = Day6::parse(&contents).unwrap();
+
+        assert_eq!(Day6::part_1(&input).unwrap(), 41);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day6::YEAR, Day6::DAY, "input.txt").unwrap();
+        let input = Day6::parse(&contents).unwrap();
+
+        assert_eq!(Day6::part_1(&input).unwrap(), 5239);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day6::YEAR, Day6::DAY, "example.txt").unwrap();
+        let input = Day6::parse(&contents).unwrap();
+
+        assert_eq!(Day6::part_2(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day6::YEAR, Day6::DAY, "input.txt").unwrap();
+        let input = Day6::parse(&contents).unwrap();
+
+        assert_eq!(Day6::part_2(&input).unwrap(), 1753);
+    }
+}
@@ -0,0 +1,153 @@
+use utilities::{PuzzleResult, Solution};
+
+type Equations = Vec<(usize, Vec<usize>)>;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 7;
+
+    type Input = Equations;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Equations> {
+        utilities::lines(contents)
+            .map(|line| {
+                let Some((result, operands)) = line.text.split_once(':') else {
+                    return Err(line.error("expected an equation like 190: 10 19"));
+                };
+
+                let result = line.parse::<usize>(result)?;
+                let operands = operands
+                    .split_ascii_whitespace()
+                    .map(|s| line.parse::<usize>(s));
+
+                Ok((result, operands.collect::<PuzzleResult<_>>()?))
+            })
+            .collect()
+    }
+
+    fn part_1(equations: &Equations) -> PuzzleResult<usize> {
+        Ok(equations
+            .iter()
+            .filter(|(result, operands)| try_evaluate(*result, operands, false))
+            .map(|(result, _)| result)
+            .sum())
+    }
+
+    fn part_2(equations: &Equations) -> PuzzleResult<usize> {
+        Ok(equations
+            .iter()
+            .filter(|(result, operands)| try_evaluate(*result, operands, true))
+            .map(|(result, _)| result)
+            .sum())
+    }
+}
+
+enum Operation {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+fn try_evaluate(result: usize, operands: &[usize], use_concatenation: bool) -> bool {
+    let all_operations = [Operation::Concatenate, Operation::Multiply, Operation::Add];
+
+    let available_operations = if use_concatenation {
+        &all_operations[..]
+    } else {
+        &all_operations[1..]
+    };
+
+    try_evaluate_helper(result, operands, available_operations)
+}
+
+fn try_evaluate_helper(
+    value: usize,
+    operands: &[usize],
+    available_operations: &[Operation],
+) -> bool {
+    if operands.is_empty() {
+        return value == 0;
+    }
+
+    let last_idx = operands.len() - 1;
+    let operand = operands[last_idx];
+    let next_operands = &operands[..last_idx];
+
+    for operation in available_operations {
+        let Some(new_value) = apply_operation(value, operand, operation) else {
+            continue;
+        };
+
+        if try_evaluate_helper(new_value, next_operands, available_operations) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn apply_operation(value: usize, operand: usize, operation: &Operation) -> Option<usize> {
+    match operation {
+        Operation::Add if value >= operand => Some(value - operand),
+        Operation::Multiply if value.is_multiple_of(operand) => Some(value / operand),
+        Operation::Concatenate => remove_concatenation(value, operand),
+        _ => None,
+    }
+}
+
+fn remove_concatenation(value: usize, operand: usize) -> Option<usize> {
+    let mut value = value;
+    let mut operand = operand;
+
+    while operand != 0 {
+        if operand % 10 != value % 10 {
+            return None;
+        }
+
+        operand /= 10;
+        value /= 10;
+    }
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day7::YEAR, Day7::DAY, "example.txt").unwrap();
+        let input = Day7::parse(&contents).unwrap();
+
+        assert_eq!(Day7::part_1(&input).unwrap(), 3749);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day7::YEAR, Day7::DAY, "input.txt").unwrap();
+        let input = Day7::parse(&contents).unwrap();
+
+        assert_eq!(Day7::part_1(&input).unwrap(), 20281182715321);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day7::YEAR, Day7::DAY, "example.txt").unwrap();
+        let input = Day7::parse(&contents).unwrap();
+
+        assert_eq!(Day7::part_2(&input).unwrap(), 11387);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day7::YEAR, Day7::DAY, "input.txt").unwrap();
+        let input = Day7::parse(&contents).unwrap();
+
+        assert_eq!(Day7::part_2(&input).unwrap(), 159490400628354);
+    }
+}
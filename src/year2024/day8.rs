@@ -0,0 +1,157 @@
+use std::collections::{HashMap, HashSet};
+
+use utilities::grid::Grid;
+use utilities::point::Point;
+use utilities::{PuzzleResult, Solution};
+
+type Antennas = HashMap<char, Vec<Point>>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 8;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Grid<char>> {
+        Grid::parse(contents, Ok)
+    }
+
+    fn part_1(grid: &Grid<char>) -> PuzzleResult<usize> {
+        let antennas = find_antennas(grid);
+
+        Ok(find_antinodes(grid, &antennas, false))
+    }
+
+    fn part_2(grid: &Grid<char>) -> PuzzleResult<usize> {
+        let antennas = find_antennas(grid);
+
+        Ok(find_antinodes(grid, &antennas, true))
+    }
+}
+
+fn find_antennas(grid: &Grid<char>) -> Antennas {
+    let mut antennas: Antennas = HashMap::new();
+
+    for (position, c) in grid.iter() {
+        if c == &'.' {
+            continue;
+        }
+
+        // This spot contains an antenna.
+        antennas
+            .entry(*c)
+            .and_modify(|positions| positions.push(position))
+            .or_insert(vec![position]);
+    }
+
+    antennas
+}
+
+fn find_antinodes(grid: &Grid<char>, antennas: &Antennas, resonant: bool) -> usize {
+    let mut antinodes = HashSet::new();
+
+    for positions in antennas.values() {
+        // Find all pairs of positions.
+        for i in 0..(positions.len() - 1) {
+            for j in (i + 1)..positions.len() {
+                let pairs = find_antinode_pairs(&positions[i], &positions[j], grid, resonant);
+
+                antinodes.extend(pairs);
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+fn find_antinode_pairs(
+    position1: &Point,
+    position2: &Point,
+    grid: &Grid<char>,
+    resonant: bool,
+) -> Vec<Point> {
+    let mut antinodes = vec![];
+
+    let delta = *position2 - *position1;
+
+    // Walk "negative" from position1.
+    let mut antinode = *position1;
+
+    while let Some(next) = grid.offset(antinode, -delta) {
+        antinodes.push(next);
+        antinode = next;
+
+        if !resonant {
+            break;
+        }
+    }
+
+    // Walk "positive" from position2.
+    let mut antinode = *position2;
+
+    while let Some(next) = grid.offset(antinode, delta) {
+        antinodes.push(next);
+        antinode = next;
+
+        if !resonant {
+            break;
+        }
+    }
+
+    // If resonant, include the two antennas.
+    if resonant {
+        antinodes.push(*position1);
+        antinodes.push(*position2);
+    }
+
+    antinodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_1() {
+        let contents = utilities::read_file_data(Day8::YEAR, Day8::DAY, "example.txt").unwrap();
+        let input = Day8::parse(&contents).unwrap();
+
+        assert_eq!(Day8::part_1(&input).unwrap(), 14);
+    }
+
+    #[test]
+    fn test_input_part_1() {
+        let contents = utilities::read_file_data(Day8::YEAR, Day8::DAY, "input.txt").unwrap();
+        let input = Day8::parse(&contents).unwrap();
+
+        assert_eq!(Day8::part_1(&input).unwrap(), 222);
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let contents = utilities::read_file_data(Day8::YEAR, Day8::DAY, "example.txt").unwrap();
+        let input = Day8::parse(&contents).unwrap();
+
+        assert_eq!(Day8::part_2(&input).unwrap(), 34);
+    }
+
+    #[test]
+    fn test_example2_part_2() {
+        let contents = utilities::read_file_data(Day8::YEAR, Day8::DAY, "example2.txt").unwrap();
+        let input = Day8::parse(&contents).unwrap();
+
+        assert_eq!(Day8::part_2(&input).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let contents = utilities::read_file_data(Day8::YEAR, Day8::DAY, "input.txt").unwrap();
+        let input = Day8::parse(&contents).unwrap();
+
+        assert_eq!(Day8::part_2(&input).unwrap(), 884);
+    }
+}
@@ -1,18 +1,64 @@
-const DAY: usize = 9;
+use utilities::{PuzzleResult, Solution};
 
-pub fn run() {
-    utilities::run_puzzle(DAY, part_1, part_2);
-}
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: usize = super::YEAR;
+    const DAY: usize = 9;
+
+    type Input = Vec<Section>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> PuzzleResult<Vec<Section>> {
+        let mut section_list = vec![];
+
+        let mut is_file = true;
+        let mut current_file_id: usize = 0;
+        let mut current_position = 0;
+        for line in utilities::lines(contents) {
+            for (idx, c) in line.text.char_indices() {
+                let Some(length) = c.to_digit(10) else {
+                    return Err(line.error_at(&line.text[idx..], format!("invalid digit '{c}'")));
+                };
+                let length = length as usize;
+
+                let file_id = if is_file {
+                    let file_id = current_file_id;
+                    current_file_id += 1;
+                    Some(file_id)
+                } else {
+                    None
+                };
+
+                let section = Section::new(file_id, current_position, length);
+
+                section_list.push(section);
+
+                current_position += length;
+
+                is_file = !is_file;
+            }
+        }
+
+        Ok(section_list)
+    }
+
+    fn part_1(section_list: &Vec<Section>) -> PuzzleResult<usize> {
+        let block_list = defragment_sections(section_list);
+
+        Ok(calculate_checksum(&block_list))
+    }
 
-fn part_1(contents: &str) -> usize {
-    let section_list = create_section_list(contents);
-    let block_list = defragment_sections(&section_list);
+    fn part_2(section_list: &Vec<Section>) -> PuzzleResult<usize> {
+        let defragmented_sections = defragment_sections_v2(section_list);
 
-    calculate_checksum(&block_list)
+        Ok(calculate_checksum_v2(&defragmented_sections))
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Section {
+pub struct Section {
     file_id: Option<usize>,
     start: usize,
     len: usize,
@@ -28,35 +74,6 @@ impl Section {
     }
 }
 
-fn create_section_list(contents: &str) -> Vec<Section> {
-    let mut section_list = vec![];
-
-    let mut is_file = true;
-    let mut current_file_id: usize = 0;
-    let mut current_position = 0;
-    for c in contents.chars() {
-        let length = c.to_digit(10).unwrap() as usize;
-
-        let file_id = if is_file {
-            let file_id = current_file_id;
-            current_file_id += 1;
-            Some(file_id)
-        } else {
-            None
-        };
-
-        let section = Section::new(file_id, current_position, length);
-
-        section_list.push(section);
-
-        current_position += length;
-
-        is_file = !is_file;
-    }
-
-    section_list
-}
-
 fn defragment_sections(section_list: &Vec<Section>) -> Vec<usize> {
     let mut block_list = vec![];
 
@@ -125,13 +142,6 @@ fn calculate_checksum(block_list: &[usize]) -> usize {
         .fold(0, |acc, (idx, val)| acc + (idx * val))
 }
 
-fn part_2(contents: &str) -> usize {
-    let section_list = create_section_list(contents);
-    let defragmented_sections = defragment_sections_v2(&section_list);
-
-    calculate_checksum_v2(&defragmented_sections)
-}
-
 fn defragment_sections_v2(section_list: &Vec<Section>) -> Vec<Section> {
     let mut file_sections = vec![];
     let mut empty_sections = vec![];
@@ -191,29 +201,33 @@ mod tests {
 
     #[test]
     fn test_example_part_1() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day9::YEAR, Day9::DAY, "example.txt").unwrap();
+        let input = Day9::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 1928);
+        assert_eq!(Day9::part_1(&input).unwrap(), 1928);
     }
 
     #[test]
     fn test_input_part_1() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day9::YEAR, Day9::DAY, "input.txt").unwrap();
+        let input = Day9::parse(&contents).unwrap();
 
-        assert_eq!(part_1(&contents), 6356833654075);
+        assert_eq!(Day9::part_1(&input).unwrap(), 6356833654075);
     }
 
     #[test]
     fn test_example_part_2() {
-        let contents = utilities::read_file_data(DAY, "example.txt");
+        let contents = utilities::read_file_data(Day9::YEAR, Day9::DAY, "example.txt").unwrap();
+        let input = Day9::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 2858);
+        assert_eq!(Day9::part_2(&input).unwrap(), 2858);
     }
 
     #[test]
     fn test_input_part_2() {
-        let contents = utilities::read_file_data(DAY, "input.txt");
+        let contents = utilities::read_file_data(Day9::YEAR, Day9::DAY, "input.txt").unwrap();
+        let input = Day9::parse(&contents).unwrap();
 
-        assert_eq!(part_2(&contents), 6389911791746);
+        assert_eq!(Day9::part_2(&input).unwrap(), 6389911791746);
     }
 }
@@ -0,0 +1,237 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A global allocator that counts the allocations of each thread, so the runner can report the
+/// memory use of each part. Nothing is counted unless a binary installs it:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: utilities::alloc::CountingAllocator = utilities::alloc::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Bytes in use. Memory freed by another thread than the one that allocated it is taken off
+    /// the freeing thread, so this can go negative.
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts `grown` new bytes in one allocation, and `shrunk` bytes given back.
+fn count(grown: usize, shrunk: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+
+    // The counters are gone while the thread is being torn down, which only loses a few frees.
+    let _ = CURRENT.try_with(|current| {
+        let now = current.get() + grown as isize - shrunk as isize;
+        current.set(now);
+
+        PEAK.with(|peak| peak.set(peak.get().max(now)));
+    });
+
+    if grown > 0 {
+        let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get() + grown));
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            count(
+                new_size.saturating_sub(layout.size()),
+                layout.size().saturating_sub(new_size),
+            );
+        }
+
+        new_ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(0, layout.size());
+    }
+}
+
+/// Whether [`CountingAllocator`] is the global allocator.
+pub fn installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// The heap use of a step, on the thread it ran on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The most bytes in use at once, above what was in use before the step.
+    pub peak_bytes: usize,
+    /// Bytes allocated in total, counting only the growth when memory is reallocated.
+    pub allocated_bytes: usize,
+    /// Allocations, counting the reallocations that grew.
+    pub allocations: usize,
+}
+
+impl AllocStats {
+    /// Adds the totals of another run, keeping the highest peak.
+    pub fn add(&mut self, other: AllocStats) {
+        self.peak_bytes = self.peak_bytes.max(other.peak_bytes);
+        self.allocated_bytes += other.allocated_bytes;
+        self.allocations += other.allocations;
+    }
+
+    /// The totals spread over `runs` runs, for steps that were run more than once. The peak is
+    /// left alone, as it's already the peak of a single run.
+    pub fn per_run(self, runs: usize) -> Self {
+        let runs = runs.max(1);
+
+        Self {
+            peak_bytes: self.peak_bytes,
+            allocated_bytes: self.allocated_bytes / runs,
+            allocations: self.allocations / runs,
+        }
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} in {} allocations",
+            Bytes(self.peak_bytes),
+            Bytes(self.allocated_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// A byte count in the largest unit that keeps it at least 1.
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.0 as f64;
+
+        for unit in ["B", "KiB", "MiB"] {
+            if value < 1024.0 {
+                return match unit {
+                    "B" => write!(f, "{} B", self.0),
+                    _ => write!(f, "{value:.1} {unit}"),
+                };
+            }
+
+            value /= 1024.0;
+        }
+
+        write!(f, "{value:.1} GiB")
+    }
+}
+
+/// Runs `f`, counting what it allocates on this thread. There are no stats when
+/// [`CountingAllocator`] isn't installed.
+pub fn measure<T, F>(f: F) -> (T, Option<AllocStats>)
+where
+    F: FnOnce() -> T,
+{
+    if !installed() {
+        return (f(), None);
+    }
+
+    let start = CURRENT.with(Cell::get);
+    PEAK.with(|peak| peak.set(start));
+    let allocated = ALLOCATED.with(Cell::get);
+    let allocations = ALLOCATIONS.with(Cell::get);
+
+    let result = f();
+
+    let stats = AllocStats {
+        peak_bytes: (PEAK.with(Cell::get) - start).max(0) as usize,
+        allocated_bytes: ALLOCATED.with(Cell::get) - allocated,
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+    };
+
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        let (sum, stats) = measure(|| {
+            let numbers = (0..1000u64).collect::<Vec<_>>();
+            numbers.iter().sum::<u64>()
+        });
+
+        let stats = stats.unwrap();
+
+        assert_eq!(sum, 499_500);
+        assert!(stats.peak_bytes >= 8000);
+        assert!(stats.allocated_bytes >= 8000);
+        assert!(stats.allocations >= 1);
+
+        let (_, stats) = measure(|| 1 + 1);
+        assert_eq!(stats, Some(AllocStats::default()));
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 30).to_string(), "3.0 GiB");
+    }
+
+    #[test]
+    fn test_render_stats() {
+        let stats = AllocStats {
+            peak_bytes: 2 << 20,
+            allocated_bytes: 30_000,
+            allocations: 20,
+        };
+
+        assert_eq!(
+            stats.to_string(),
+            "peak 2.0 MiB, 29.3 KiB in 20 allocations"
+        );
+        assert_eq!(stats.per_run(4).allocations, 5);
+
+        let mut total = stats;
+        total.add(AllocStats {
+            peak_bytes: 100,
+            allocated_bytes: 100,
+            allocations: 1,
+        });
+        assert_eq!((total.peak_bytes, total.allocations), (2 << 20, 21));
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The file name of the answers kept next to a set of inputs.
+pub const ANSWERS_FILE_NAME: &str = "answers.txt";
+
+const HEADER: &str = "# Expected answers, one per line: <day> <part> <answer>";
+
+/// Known answers for one set of inputs, keyed by day and part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(usize, usize), String>,
+}
+
+/// How an answer compares to the one on record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There's no answer on record.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl Answers {
+    /// Reads an answers file. A missing file has no answers yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{error}", path.display()),
+                )
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let pieces = line.splitn(3, ' ').map(str::trim).collect::<Vec<_>>();
+
+            let [day, part, answer] = pieces[..] else {
+                return Err(format!("{}: expected '<day> <part> <answer>'", idx + 1));
+            };
+
+            let (Ok(day), Ok(part @ 1..=2)) = (day.parse::<usize>(), part.parse::<usize>()) else {
+                return Err(format!("{}: invalid day or part in '{line}'", idx + 1));
+            };
+
+            answers.insert(day, part, answer);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: usize, part: usize, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Compares `answer` to the one on record. An error is a failure when an answer is known.
+    pub fn check(&self, day: usize, part: usize, answer: Result<&str, &str>) -> Verdict {
+        match (self.get(day, part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Ok(answer)) if expected == answer => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{day} {part} {answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_write() {
+        let answers = Answers::parse("# comment\n17 1 7,5,4,3\n1 2 31\n\n1 1 11\n").unwrap();
+
+        assert_eq!(answers.get(17, 1), Some("7,5,4,3"));
+        assert_eq!(answers.get(2, 1), None);
+        assert_eq!(
+            answers.to_string(),
+            format!("{HEADER}\n1 1 11\n1 2 31\n17 1 7,5,4,3\n")
+        );
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("1 11").is_err());
+        assert!(Answers::parse("1 3 11").is_err());
+        assert!(Answers::parse("x 1 11").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert(1, 1, "11");
+
+        assert_eq!(answers.check(1, 1, Ok("11")), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 1, Ok("12")),
+            Verdict::Fail {
+                expected: "11".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(1, 1, Err("parse error")),
+            Verdict::Fail {
+                expected: "11".to_string()
+            }
+        );
+        assert_eq!(answers.check(1, 2, Ok("31")), Verdict::Unknown);
+    }
+}
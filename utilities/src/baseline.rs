@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::DayReport;
+
+/// Where named baselines are kept, relative to the repository root.
+pub const BASELINES_DIR: &str = "baselines";
+
+const HEADER: &str = "# Benchmark medians, one per line: <year> <day> <part> <nanoseconds>";
+
+/// Median runtimes of each day and part from a benchmark run, to compare later runs against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    timings: BTreeMap<(usize, usize, usize), Duration>,
+}
+
+/// How one part's runtime changed between two baselines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub before: Duration,
+    /// Missing when the part isn't in the newer baseline.
+    pub after: Option<Duration>,
+}
+
+impl Comparison {
+    /// The change in runtime as a percentage of the old runtime, positive when slower.
+    pub fn change(&self) -> Option<f64> {
+        let after = self.after?;
+        let before = self.before.as_secs_f64();
+
+        if before == 0.0 {
+            return None;
+        }
+
+        Some((after.as_secs_f64() - before) / before * 100.0)
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// The file for a baseline: `name` itself if it looks like a path, otherwise
+/// `baselines/<name>.txt`.
+pub fn baseline_path(name: &str) -> PathBuf {
+    let path = Path::new(name);
+
+    if path.extension().is_some() || path.components().count() > 1 {
+        path.to_path_buf()
+    } else {
+        Path::new(BASELINES_DIR).join(format!("{name}.txt"))
+    }
+}
+
+impl Baseline {
+    /// Reads a baseline. A missing file is an empty baseline.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{error}", path.display()),
+                )
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut baseline = Self::default();
+
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let numbers = line
+                .split_ascii_whitespace()
+                .map(|value| value.parse::<u64>().ok())
+                .collect::<Vec<_>>();
+
+            let [Some(year), Some(day), Some(part), Some(nanos)] = numbers[..] else {
+                return Err(format!(
+                    "{}: expected '<year> <day> <part> <nanoseconds>'",
+                    idx + 1
+                ));
+            };
+
+            baseline.insert(
+                year as usize,
+                day as usize,
+                part as usize,
+                Duration::from_nanos(nanos),
+            );
+        }
+
+        Ok(baseline)
+    }
+
+    /// Writes the baseline, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timings.is_empty()
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: usize) -> Option<Duration> {
+        self.timings.get(&(year, day, part)).copied()
+    }
+
+    pub fn insert(&mut self, year: usize, day: usize, part: usize, duration: Duration) {
+        self.timings.insert((year, day, part), duration);
+    }
+
+    /// The year and day of every day that has a timing in this baseline.
+    pub fn days(&self) -> Vec<(usize, usize)> {
+        let mut days = self
+            .timings
+            .keys()
+            .map(|(year, day, _)| (*year, *day))
+            .collect::<Vec<_>>();
+        days.dedup();
+        days
+    }
+
+    /// Adds the timings of the solved parts in the report, replacing any older ones.
+    pub fn add_report(&mut self, report: &DayReport) {
+        for part in &report.parts {
+            if let (Ok(_), Some(timing)) = (&part.answer, part.timing) {
+                self.insert(report.year, report.day, part.part, timing.duration());
+            }
+        }
+    }
+
+    /// Takes every timing in `newer`, replacing the ones for the same parts.
+    pub fn merge(&mut self, newer: &Baseline) {
+        self.timings.extend(&newer.timings);
+    }
+
+    /// Compares every part in this baseline to the same part in `after`.
+    pub fn compare(&self, after: &Baseline) -> Vec<Comparison> {
+        self.timings
+            .iter()
+            .map(|(&(year, day, part), &before)| Comparison {
+                year,
+                day,
+                part,
+                before,
+                after: after.get(year, day, part),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+
+        for ((year, day, part), duration) in &self.timings {
+            writeln!(f, "{year} {day} {part} {}", duration.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_write() {
+        let baseline = Baseline::parse(
+            "# comment\n2024 23 2 7825000000\n2024 20 2 4649000000\n2023 23 2 100\n",
+        )
+        .unwrap();
+
+        assert_eq!(baseline.get(2024, 23, 2), Some(Duration::from_millis(7825)));
+        assert_eq!(baseline.get(2023, 23, 2), Some(Duration::from_nanos(100)));
+        assert_eq!(baseline.days(), [(2023, 23), (2024, 20), (2024, 23)]);
+        assert_eq!(Baseline::parse(&baseline.to_string()), Ok(baseline));
+        assert!(Baseline::parse("2024 1 1 fast").is_err());
+        assert!(Baseline::parse("1 1 1000").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = Baseline::parse("2024 1 1 1000\n2024 1 2 2000\n2024 2 1 500\n").unwrap();
+        let after = Baseline::parse("2024 1 1 1150\n2024 1 2 1000\n2023 2 1 500\n").unwrap();
+
+        let comparisons = before.compare(&after);
+        let changes = comparisons
+            .iter()
+            .map(|comparison| comparison.change().map(f64::round))
+            .collect::<Vec<_>>();
+
+        assert_eq!(changes, [Some(15.0), Some(-50.0), None]);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(20.0));
+        assert!(!comparisons[1].is_regression(10.0));
+        assert!(!comparisons[2].is_regression(10.0));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut baseline = Baseline::parse("2024 1 1 1000\n2024 2 1 500\n").unwrap();
+        baseline.merge(&Baseline::parse("2024 1 1 900\n2023 1 1 100\n").unwrap());
+
+        assert_eq!(
+            baseline,
+            Baseline::parse("2023 1 1 100\n2024 1 1 900\n2024 2 1 500\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_baseline_path() {
+        assert_eq!(
+            baseline_path("main"),
+            Path::new(BASELINES_DIR).join("main.txt")
+        );
+        assert_eq!(baseline_path("old/run.txt"), Path::new("old/run.txt"));
+    }
+}
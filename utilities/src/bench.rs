@@ -0,0 +1,153 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// How many times each step is run when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts, to warm up caches and the allocator.
+    pub warmup: usize,
+    /// Timed runs that the statistics are calculated from.
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Some(Self {
+            samples: count,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}, std dev {:?} ({} samples)",
+            self.min, self.median, self.mean, self.std_dev, self.samples
+        )
+    }
+}
+
+/// Runs `f` for the warm-up iterations and then the timed ones, returning the last result along
+/// with the timing statistics. Stops early if `f` fails, since there is nothing useful to time.
+pub fn bench<T, E, F>(options: &BenchOptions, mut f: F) -> Result<(T, Stats), E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    for _ in 0..options.warmup {
+        f()?;
+    }
+
+    let iterations = options.iterations.max(1);
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+
+    for _ in 0..iterations {
+        let now = Instant::now();
+        let value = f()?;
+        samples.push(now.elapsed());
+
+        result = Some(value);
+    }
+
+    let stats = Stats::from_samples(&samples).unwrap();
+
+    Ok((result.unwrap(), stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.std_dev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_counts_iterations() {
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: 5,
+        };
+
+        let mut calls = 0;
+        let (result, stats) = bench(&options, || -> Result<usize, ()> {
+            calls += 1;
+            Ok(calls)
+        })
+        .unwrap();
+
+        assert_eq!(result, 7);
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn test_bench_stops_on_error() {
+        let mut calls = 0;
+        let result = bench(&BenchOptions::default(), || -> Result<(), &str> {
+            calls += 1;
+            Err("failed")
+        });
+
+        assert_eq!(result.unwrap_err(), "failed");
+        assert_eq!(calls, 1);
+    }
+}
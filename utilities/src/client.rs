@@ -0,0 +1,177 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Where the puzzles are served from, unless overridden.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable that overrides the base URL, to talk to a local server instead.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variable with the session cookie of a logged in account.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The file the session cookie is read from when `AOC_SESSION` isn't set.
+pub const SESSION_FILE: &str = ".aoc_session";
+
+/// The shortest time between two requests to the server.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal solutions runner)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The server couldn't be reached, or the response couldn't be read.
+    Transport(String),
+    /// The server answered with an error status.
+    Status { status: u16, body: String },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Transport(message) => write!(f, "request failed: {message}"),
+            ClientError::Status { status: 400, .. } => {
+                write!(
+                    f,
+                    "the server rejected the session (400), it may have expired"
+                )
+            }
+            ClientError::Status { status: 404, .. } => {
+                write!(f, "not found (404), the puzzle may not be unlocked yet")
+            }
+            ClientError::Status { status, body } => {
+                write!(f, "the server answered {status}: {}", body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// A logged in connection to the puzzle server. Requests are spaced out by at least the minimum
+/// interval, so fetching many days in a row doesn't hammer the server.
+pub struct Client {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: None,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Downloads the puzzle input of `day` in `year`.
+    pub fn input(&mut self, year: usize, day: usize) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// Submits an answer, returning the page the server responds with.
+    pub fn submit(
+        &mut self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        self.throttle();
+
+        let response = self
+            .agent
+            .post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        read_response(response)
+    }
+
+    fn get(&mut self, path: &str) -> Result<String, ClientError> {
+        self.throttle();
+
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+
+    /// Waits until the minimum interval since the last request has passed.
+    fn throttle(&mut self) {
+        if let Some(elapsed) = self.last_request.map(|last| last.elapsed()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        self.last_request = Some(Instant::now());
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string())),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(error) => Err(ClientError::Transport(error.to_string())),
+    }
+}
+
+/// The base URL from `AOC_BASE_URL`, or the real server if it isn't set.
+pub fn base_url() -> String {
+    match env::var(BASE_URL_ENV) {
+        Ok(url) if !url.is_empty() => url,
+        _ => DEFAULT_BASE_URL.to_string(),
+    }
+}
+
+/// The session cookie from `AOC_SESSION`, or else from `file` (`.aoc_session` by default).
+pub fn read_session(file: Option<&Path>) -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let file = file.unwrap_or(Path::new(SESSION_FILE));
+
+    match fs::read_to_string(file) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(format!("{} is empty", file.display())),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Err(format!(
+            "No session found, set {SESSION_ENV} or put the session cookie in {}",
+            file.display()
+        )),
+        Err(error) => Err(format!("Couldn't read {}: {error}", file.display())),
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::point::Vector;
+
+/// One of the four straight directions on a grid, with north pointing up the rows.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Iterates over `ALL`.
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// A single step in this direction.
+    pub fn offset(self) -> Vector {
+        match self {
+            Self::North => Vector::new(-1, 0),
+            Self::East => Vector::new(0, 1),
+            Self::South => Vector::new(1, 0),
+            Self::West => Vector::new(0, -1),
+        }
+    }
+
+    /// The direction of a single straight step, like the one between two neighbors.
+    pub fn from_offset(offset: Vector) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == offset)
+    }
+
+    /// The arrow puzzles draw for this direction, one of `^`, `>`, `v` and `<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        }
+    }
+
+    /// The compass letter for this direction, one of `N`, `E`, `S` and `W`.
+    pub fn letter(self) -> char {
+        match self {
+            Self::North => 'N',
+            Self::East => 'E',
+            Self::South => 'S',
+            Self::West => 'W',
+        }
+    }
+}
+
+/// Reads either an arrow or a compass letter, so it can be used with
+/// [`Grid::parse`](crate::grid::Grid::parse).
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        match c {
+            '^' | 'N' => Ok(Self::North),
+            '>' | 'E' => Ok(Self::East),
+            'v' | 'S' => Ok(Self::South),
+            '<' | 'W' => Ok(Self::West),
+            _ => Err(format!("invalid direction '{c}'")),
+        }
+    }
+}
+
+/// Writes the arrow.
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// One of the eight directions on a grid, including the diagonals.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Iterates over `ALL`.
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// The direction an eighth of a turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// A single step in this direction, which moves a row and a column at once on the diagonals.
+    pub fn offset(self) -> Vector {
+        match self {
+            Self::North => Vector::new(-1, 0),
+            Self::NorthEast => Vector::new(-1, 1),
+            Self::East => Vector::new(0, 1),
+            Self::SouthEast => Vector::new(1, 1),
+            Self::South => Vector::new(1, 0),
+            Self::SouthWest => Vector::new(1, -1),
+            Self::West => Vector::new(0, -1),
+            Self::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    /// Whether this is one of the four diagonal directions.
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The compass points for this direction, like `N` or `SW`.
+    pub fn letters(self) -> &'static str {
+        match self {
+            Self::North => "N",
+            Self::NorthEast => "NE",
+            Self::East => "E",
+            Self::SouthEast => "SE",
+            Self::South => "S",
+            Self::SouthWest => "SW",
+            Self::West => "W",
+            Self::NorthWest => "NW",
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+/// Reads the compass points, like `N` or `SW`.
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.letters() == s)
+            .ok_or_else(|| format!("invalid direction '{s}'"))
+    }
+}
+
+/// Reads a compass letter for one of the straight directions, like [`Direction`] does.
+impl TryFrom<char> for Direction8 {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        Direction::try_from(c).map(Self::from)
+    }
+}
+
+/// Writes the compass points.
+impl fmt::Display for Direction8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letters())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Vector::default()
+            );
+            assert_eq!(Direction::from_offset(direction.offset()), Some(direction));
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::from_offset(Vector::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_chars() {
+        let arrows = Direction::all().map(|direction| direction.arrow());
+        assert_eq!(arrows.collect::<String>(), "^>v<");
+
+        let letters = Direction::all().map(|direction| direction.letter());
+        assert_eq!(letters.collect::<String>(), "NESW");
+
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(direction.arrow()), Ok(direction));
+            assert_eq!(Direction::try_from(direction.letter()), Ok(direction));
+        }
+
+        assert_eq!(Direction::West.to_string(), "<");
+        assert_eq!(
+            Direction::try_from('x').unwrap_err(),
+            "invalid direction 'x'"
+        );
+    }
+
+    #[test]
+    fn test_eight_directions() {
+        assert_eq!(Direction8::all().filter(|d| d.is_diagonal()).count(), 4);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+        assert_eq!(Direction8::SouthWest.opposite(), Direction8::NorthEast);
+        assert_eq!(Direction8::SouthWest.offset(), Vector::new(1, -1));
+        assert_eq!(Direction8::from(Direction::West), Direction8::West);
+        assert_eq!(Direction8::SouthEast.to_string(), "SE");
+
+        for direction in Direction8::ALL {
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Vector::default()
+            );
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+
+        assert_eq!(Direction8::try_from('W'), Ok(Direction8::West));
+        assert_eq!(Direction8::try_from('<'), Ok(Direction8::West));
+        assert!("NNE".parse::<Direction8>().is_err());
+    }
+}
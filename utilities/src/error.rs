@@ -0,0 +1,207 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// The input couldn't be read.
+    Io(io::Error),
+    /// The input was read, but isn't in the expected format.
+    Parse(String),
+    /// The input parsed, but the puzzle couldn't be solved with it.
+    Solve(String),
+    /// Solving took longer than the timeout, and was given up on.
+    Timeout(Duration),
+    /// The solution panicked, with this message.
+    Panic(String),
+}
+
+/// An error while reading, parsing or solving a puzzle, with as much context about where it
+/// happened as is known.
+#[derive(Debug)]
+pub struct PuzzleError {
+    pub kind: ErrorKind,
+    pub day: Option<usize>,
+    pub file: Option<String>,
+    /// 1-based line number in the input.
+    pub line: Option<usize>,
+    /// 1-based column number in the input.
+    pub column: Option<usize>,
+}
+
+impl PuzzleError {
+    fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            day: None,
+            file: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn io(error: io::Error) -> Self {
+        Self::new(ErrorKind::Io(error))
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse(message.into()))
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve(message.into()))
+    }
+
+    pub fn timeout(timeout: Duration) -> Self {
+        Self::new(ErrorKind::Timeout(timeout))
+    }
+
+    pub fn panic(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Panic(message.into()))
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Fills in the day and file, keeping any that were already set.
+    pub fn with_context(mut self, day: usize, file: &str) -> Self {
+        self.day.get_or_insert(day);
+        self.file.get_or_insert_with(|| file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}: ")?;
+        }
+
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{line}:{column}: ")?,
+            (Some(line), None) => write!(f, "{line}: ")?,
+            _ if self.file.is_some() => write!(f, " ")?,
+            _ => {}
+        }
+
+        match &self.kind {
+            ErrorKind::Io(error) => write!(f, "{error}"),
+            ErrorKind::Parse(message) => write!(f, "parse error: {message}"),
+            ErrorKind::Solve(message) => write!(f, "{message}"),
+            ErrorKind::Timeout(timeout) => write!(f, "TIMEOUT after {timeout:?}"),
+            ErrorKind::Panic(message) => write!(f, "PANIC: {message}"),
+        }
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PuzzleError {
+    fn from(error: io::Error) -> Self {
+        Self::io(error)
+    }
+}
+
+/// A line of puzzle input, which knows its position for error reporting.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Parses `field`, which should be a slice of this line so the error can point at its column.
+    pub fn parse<T: FromStr>(&self, field: &str) -> PuzzleResult<T> {
+        field
+            .parse::<T>()
+            .map_err(|_| self.error_at(field, format!("invalid value '{field}'")))
+    }
+
+    /// An error pointing at the start of this line.
+    pub fn error(&self, message: impl Into<String>) -> PuzzleError {
+        PuzzleError::parse(message).at(self.number, 1)
+    }
+
+    /// An error pointing at `field`, which should be a slice of this line.
+    pub fn error_at(&self, field: &str, message: impl Into<String>) -> PuzzleError {
+        PuzzleError::parse(message).at(self.number, self.column_of(field))
+    }
+
+    fn column_of(&self, field: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (field.as_ptr() as usize).wrapping_sub(start);
+
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
+
+/// The lines of `contents` with their line numbers, ignoring any trailing blank lines.
+pub fn lines(contents: &str) -> impl Iterator<Item = Line<'_>> {
+    contents
+        .trim_end_matches(['\r', '\n'])
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Line {
+            number: idx + 1,
+            text,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let contents = "1 2\n3 x\n\n";
+        let line = lines(contents).nth(1).unwrap();
+        let pieces = line.text.split_ascii_whitespace().collect::<Vec<_>>();
+
+        assert_eq!(line.parse::<usize>(pieces[0]).unwrap(), 3);
+
+        let error = line.parse::<usize>(pieces[1]).unwrap_err();
+
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        assert_eq!(
+            error.with_context(1, "input.txt").to_string(),
+            "day 1: input.txt:2:3: parse error: invalid value 'x'"
+        );
+    }
+
+    #[test]
+    fn test_trailing_lines_ignored() {
+        assert_eq!(lines("a\n\nb\n\n\n").count(), 3);
+    }
+
+    #[test]
+    fn test_context_is_kept() {
+        let error = PuzzleError::solve("no path")
+            .with_context(16, "a.txt")
+            .with_context(17, "b.txt");
+
+        assert_eq!(error.to_string(), "day 16: a.txt: no path");
+    }
+}
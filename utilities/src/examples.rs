@@ -0,0 +1,240 @@
+/// An example from a puzzle description, with the answers it's said to have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub text: String,
+    /// The guessed answers of part 1 and part 2.
+    pub answers: [Option<String>; 2],
+}
+
+/// A piece of a puzzle description: a `<pre><code>` block, or an emphasized value like
+/// `<code><em>11</em></code>` in the text around them.
+#[derive(Debug, PartialEq, Eq)]
+enum Piece {
+    Block {
+        text: String,
+        /// Whether the text since the previous block introduces it as an example.
+        introduced: bool,
+    },
+    Emphasized(String),
+}
+
+/// Finds the examples in a saved puzzle description page. Each part's `<article>` is read in
+/// turn: blocks introduced as an example ("For example:", "a larger example:") are taken as
+/// examples, and the last emphasized value before the next example is guessed to be its answer.
+/// When a part has no example of its own, its answer is guessed to be for the last example that
+/// had one.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+
+    for (part, article) in articles(html).into_iter().enumerate().take(2) {
+        let pieces = pieces(article);
+        let any_introduced = pieces.iter().any(|piece| {
+            matches!(
+                piece,
+                Piece::Block {
+                    introduced: true,
+                    ..
+                }
+            )
+        });
+        let mut current = None;
+        let mut answer = None;
+
+        for piece in pieces {
+            match piece {
+                Piece::Block { text, introduced } if introduced || !any_introduced => {
+                    set_answer(&mut examples, current, part, answer.take());
+
+                    let idx = match examples.iter().position(|example| example.text == text) {
+                        Some(idx) => idx,
+                        None => {
+                            examples.push(Example {
+                                text,
+                                answers: [None, None],
+                            });
+                            examples.len() - 1
+                        }
+                    };
+
+                    current = Some(idx);
+                }
+                Piece::Block { .. } => {}
+                Piece::Emphasized(value) => answer = Some(value),
+            }
+        }
+
+        if current.is_none() {
+            current = examples
+                .iter()
+                .rposition(|example| example.answers[0].is_some());
+        }
+
+        set_answer(&mut examples, current, part, answer);
+    }
+
+    examples
+}
+
+fn set_answer(examples: &mut [Example], idx: Option<usize>, part: usize, answer: Option<String>) {
+    if let (Some(idx), Some(answer)) = (idx, answer) {
+        examples[idx].answers[part] = Some(answer);
+    }
+}
+
+/// The contents of each `<article>`, or the whole page if it has none.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let end = rest[start..]
+            .find("</article>")
+            .map_or(rest.len(), |end| start + end);
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    articles
+}
+
+fn pieces(article: &str) -> Vec<Piece> {
+    let mut pieces = vec![];
+    let mut rest = article;
+
+    loop {
+        let block = rest.find("<pre>");
+        let text = &rest[..block.unwrap_or(rest.len())];
+
+        pieces.extend(emphasized(text).into_iter().map(Piece::Emphasized));
+
+        let Some(start) = block else {
+            break;
+        };
+
+        let end = rest[start..]
+            .find("</pre>")
+            .map_or(rest.len(), |end| start + end);
+        let introduced = plain_text(text).to_lowercase().contains("example");
+
+        pieces.push(Piece::Block {
+            text: plain_text(&rest[start..end]),
+            introduced,
+        });
+
+        rest = &rest[(end + "</pre>".len()).min(rest.len())..];
+    }
+
+    pieces
+}
+
+/// The values in `<code><em>..</em></code>` or `<em><code>..</code></em>`, in order.
+fn emphasized(text: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = text;
+
+    loop {
+        let next = [
+            ("<code><em>", "</em></code>"),
+            ("<em><code>", "</code></em>"),
+        ]
+        .into_iter()
+        .filter_map(|(open, close)| Some((rest.find(open)?, open, close)))
+        .min_by_key(|(start, _, _)| *start);
+
+        let Some((start, open, close)) = next else {
+            break;
+        };
+
+        let value_start = start + open.len();
+        let Some(length) = rest[value_start..].find(close) else {
+            break;
+        };
+
+        values.push(plain_text(&rest[value_start..value_start + length]));
+        rest = &rest[value_start + length + close.len()..];
+    }
+
+    values
+}
+
+/// `html` without tags, and with the entities the puzzles use decoded.
+fn plain_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 12: Garden Groups ---</h2>
+<p>For example:</p>
+<pre><code>AAAA
+BB&lt;C
+</code></pre>
+<p>The regions are marked like this:</p>
+<pre><code><em>AAAA</em>
+BBCC
+</code></pre>
+<p>So, it has a total price of <code><em>140</em></code>.</p>
+<p>Here's a larger example:</p>
+<pre><code>RRRRIICCFF
+RRRRIICCCF
+</code></pre>
+<p>It has <code><em>11</em></code> regions and a total price of <code><em>1930</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The larger example from before now has a total price of <em><code>1206</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PAGE);
+
+        assert_eq!(
+            examples,
+            [
+                Example {
+                    text: "AAAA\nBB<C\n".to_string(),
+                    answers: [Some("140".to_string()), None],
+                },
+                Example {
+                    text: "RRRRIICCFF\nRRRRIICCCF\n".to_string(),
+                    answers: [Some("1930".to_string()), Some("1206".to_string())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blocks_without_introductions() {
+        let examples = extract_examples(
+            "<p>Consider:</p><pre><code>1 2\n</code></pre><p>It's <code><em>3</em></code>.</p>",
+        );
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answers[0].as_deref(), Some("3"));
+        assert!(extract_examples("<p>No examples today</p>").is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::client::{Client, ClientError};
+use crate::input::{self, INPUT_FILE_NAME};
+
+/// What fetching a day's input did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was already cached, so nothing was requested.
+    Cached(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Client(ClientError),
+    Save(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Client(error) => write!(f, "{error}"),
+            FetchError::Save(path, error) => write!(f, "couldn't save {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Where the input of `day` is cached: `<root>/{year}/day{day}/input.txt`.
+pub fn cached_input_path(root: &Path, year: usize, day: usize) -> PathBuf {
+    input::day_dir(root, year, day).join(INPUT_FILE_NAME)
+}
+
+/// Downloads the input of `day` into the cache under `root`. An input that is already cached is
+/// never downloaded again.
+pub fn fetch_input(
+    client: &mut Client,
+    root: &Path,
+    year: usize,
+    day: usize,
+) -> Result<Fetched, FetchError> {
+    let path = cached_input_path(root, year, day);
+
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day).map_err(FetchError::Client)?;
+
+    // Written next to the cache and renamed into place, so an interrupted write doesn't look like
+    // a cached input.
+    let partial = path.with_extension("txt.partial");
+    let save = |error| FetchError::Save(path.clone(), error);
+
+    fs::create_dir_all(path.parent().unwrap()).map_err(save)?;
+    fs::write(&partial, input).map_err(save)?;
+    fs::rename(&partial, &path).map_err(save)?;
+
+    Ok(Fetched::Downloaded(path))
+}
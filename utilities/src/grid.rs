@@ -0,0 +1,348 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::neighbors::Neighbors;
+use crate::point::{Point, Vector};
+use crate::{Line, PuzzleResult};
+
+/// A rectangle of cells, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with a cell for each character, turning each one into a cell with `cell`,
+    /// which returns the error message for characters that don't belong in the grid. Every line
+    /// should be as long as the first, and trailing blank lines are ignored.
+    pub fn parse<F>(contents: &str, cell: F) -> PuzzleResult<Self>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        Self::from_lines(crate::lines(contents), cell)
+    }
+
+    /// Parses a grid from `lines` like [`Grid::parse`], for inputs where the grid is only one
+    /// section.
+    pub fn from_lines<'a, I, F>(lines: I, mut cell: F) -> PuzzleResult<Self>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines {
+            let start = cells.len();
+
+            for (idx, c) in line.text.char_indices() {
+                cells.push(cell(c).map_err(|message| line.error_at(&line.text[idx..], message))?);
+            }
+
+            let row_width = cells.len() - start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error(format!(
+                        "every row should be {width} cells wide, this one is {row_width}"
+                    )));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` is inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    /// The point `vector` away from `point`, if it's inside the grid.
+    pub fn offset(&self, point: Point, vector: Vector) -> Option<Point> {
+        (point + vector).filter(|next| self.contains(*next))
+    }
+
+    /// The points a straight step away from `point` inside the grid.
+    pub fn neighbors(&self, point: Point) -> Neighbors {
+        Neighbors::four(point, self.width, self.height)
+    }
+
+    /// The points a straight or diagonal step away from `point` inside the grid.
+    pub fn neighbors8(&self, point: Point) -> Neighbors {
+        Neighbors::eight(point, self.width, self.height)
+    }
+
+    /// The neighbors of `point` whose cells pass `keep`, like the ones that aren't walls. `kind`
+    /// picks which neighbors, such as `Neighbors::four` or `Neighbors::eight`.
+    pub fn neighbors_where<'a, F>(
+        &'a self,
+        point: Point,
+        kind: fn(Point, usize, usize) -> Neighbors,
+        keep: F,
+    ) -> impl Iterator<Item = Point> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        kind(point, self.width, self.height).filter(move |neighbor| keep(&self[*neighbor]))
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.row * self.width + point.col])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.row * self.width + point.col])
+    }
+
+    /// The rows, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // `chunks` panics on a chunk size of 0, which a grid of empty rows would have.
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// The cells of column `col`, from top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let height = if col < self.width { self.height } else { 0 };
+
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    /// The columns, from left to right, each from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(|row| (0..self.width).map(move |col| Point::new(row, col)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Every point holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// The cell at `point`, which panics when it's outside the grid.
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {width}x{height} grid"))
+    }
+}
+
+/// The rows, one per line, for grids of characters and other single-character cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.S\n.#.\nE..\n\n";
+
+    fn digit(c: char) -> Result<u32, String> {
+        c.to_digit(10).ok_or(format!("invalid digit '{c}'"))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(MAP, Ok).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Point::new(0, 2)], 'S');
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&'E'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(
+            grid.offset(Point::new(0, 2), Vector::new(2, -1)),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(grid.offset(Point::new(0, 2), Vector::new(0, 1)), None);
+        assert_eq!(grid.offset(Point::new(0, 2), Vector::new(-1, 0)), None);
+        assert_eq!(grid.to_string(), "#.S\n.#.\nE..\n");
+
+        let grid = Grid::parse("123\n456\n", digit).unwrap();
+        assert_eq!(grid[Point::new(1, 1)], 5);
+
+        assert_eq!(
+            Grid::parse("123\n4x6\n", digit).unwrap_err().to_string(),
+            "2:2: parse error: invalid digit 'x'"
+        );
+        assert_eq!(
+            Grid::parse("123\n45\n", digit).unwrap_err().to_string(),
+            "2:1: parse error: every row should be 3 cells wide, this one is 2"
+        );
+        assert_eq!(Grid::parse("", digit).unwrap().height(), 0);
+
+        let mut lines = crate::lines("12\n34\n\nmoves\n");
+        let grid = Grid::from_lines(
+            lines.by_ref().take_while(|line| !line.text.is_empty()),
+            digit,
+        );
+        assert_eq!(grid.unwrap().height(), 2);
+        assert_eq!(lines.next().unwrap().text, "moves");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse(MAP, Ok).unwrap();
+
+        let rows = grid.rows().map(String::from_iter).collect::<Vec<_>>();
+        assert_eq!(rows, ["#.S", ".#.", "E.."]);
+
+        let columns = grid.columns().map(String::from_iter).collect::<Vec<_>>();
+        assert_eq!(columns, ["#.E", ".#.", "S.."]);
+
+        assert_eq!(grid.column(2).rev().collect::<String>(), "..S");
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse(MAP, Ok).unwrap();
+
+        assert_eq!(grid.find(&'S'), Some(Point::new(0, 2)));
+        assert_eq!(grid.find(&'@'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(1, 1)]
+        );
+        assert_eq!(grid.iter().filter(|(_, cell)| **cell == '.').count(), 5);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse(MAP, Ok).unwrap();
+
+        assert_eq!(grid.neighbors(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors_where(Point::new(1, 0), Neighbors::four, |cell| *cell != '#')
+                .collect::<Vec<_>>(),
+            [Point::new(2, 0)]
+        );
+        assert_eq!(
+            grid.neighbors_where(Point::new(1, 0), Neighbors::eight, |cell| *cell != '#')
+                .collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(2, 1), Point::new(2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = Grid::new(2, 3, '.');
+
+        *grid.get_mut(Point::new(2, 1)).unwrap() = '#';
+        grid[Point::new(0, 0)] = 'O';
+
+        assert!(grid.get_mut(Point::new(1, 2)).is_none());
+        assert_eq!(grid.to_string(), "O.\n..\n.#\n");
+        assert_eq!(
+            grid.map(|cell| *cell == '#').find(&true),
+            Some(Point::new(2, 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 2x3 grid")]
+    fn test_index_outside() {
+        let grid = Grid::new(2, 3, 0);
+
+        let _ = grid[Point::new(3, 0)];
+    }
+}
@@ -0,0 +1,164 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::answers::ANSWERS_FILE_NAME;
+
+/// Environment variable that overrides where puzzle inputs are read from.
+pub const INPUTS_ROOT_ENV: &str = "AOC_INPUTS";
+
+/// The committed directory with the example files for each day.
+pub const TEST_FILES_ROOT: &str = "test_files";
+
+pub const INPUT_FILE_NAME: &str = "input.txt";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<root>/{year}/day{day}/input.txt`.
+    Root(PathBuf),
+    /// An explicit file, used as-is for whichever day is run.
+    File(PathBuf),
+    /// Standard input, read to the end.
+    Stdin,
+    /// Input that is already in memory.
+    Inline(String),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Root(inputs_root())
+    }
+}
+
+impl InputSource {
+    /// Whether this source can only be used for a single day.
+    pub fn is_single_use(&self) -> bool {
+        matches!(self, InputSource::File(_) | InputSource::Stdin)
+    }
+
+    pub fn read(&self, year: usize, day: usize) -> io::Result<String> {
+        match self {
+            InputSource::Root(root) => {
+                fs::read_to_string(day_file_path(root, year, day, INPUT_FILE_NAME))
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+
+                Ok(contents)
+            }
+            InputSource::Inline(contents) => Ok(contents.clone()),
+        }
+    }
+
+    /// The answers file kept with this set of inputs for `year`, if there is one.
+    pub fn answers_path(&self, year: usize) -> Option<PathBuf> {
+        match self {
+            InputSource::Root(root) => Some(root.join(year.to_string()).join(ANSWERS_FILE_NAME)),
+            _ => None,
+        }
+    }
+
+    /// A short description of where the input for `day` is read from.
+    pub fn describe(&self, year: usize, day: usize) -> String {
+        match self {
+            InputSource::Root(root) => day_file_path(root, year, day, INPUT_FILE_NAME)
+                .display()
+                .to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "<stdin>".to_string(),
+            InputSource::Inline(_) => "<inline>".to_string(),
+        }
+    }
+}
+
+/// The inputs root from `AOC_INPUTS`, or the committed test files if it isn't set.
+pub fn inputs_root() -> PathBuf {
+    match env::var_os(INPUTS_ROOT_ENV) {
+        Some(root) if !root.is_empty() => PathBuf::from(root),
+        _ => PathBuf::from(TEST_FILES_ROOT),
+    }
+}
+
+/// The directory with a day's files under `root`: `<root>/{year}/day{day}`.
+pub fn day_dir(root: &Path, year: usize, day: usize) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{day}"))
+}
+
+/// Resolves `<root>/{year}/day{day}/<file_name>`. Examples that don't exist under `root` fall back
+/// to the committed test files, so they are still found when inputs live somewhere else. The
+/// puzzle input never falls back, since that would be someone else's input.
+pub fn day_file_path(root: &Path, year: usize, day: usize, file_name: &str) -> PathBuf {
+    let path = day_dir(root, year, day).join(file_name);
+
+    if path.exists() || file_name == INPUT_FILE_NAME {
+        return path;
+    }
+
+    let fallback = day_dir(Path::new(TEST_FILES_ROOT), year, day).join(file_name);
+
+    if fallback.exists() {
+        fallback
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_source() {
+        let source = InputSource::Inline("1 2\n3 4\n".to_string());
+
+        assert_eq!(source.read(2024, 1).unwrap(), "1 2\n3 4\n");
+        assert_eq!(source.describe(2024, 1), "<inline>");
+        assert_eq!(source.answers_path(2024), None);
+        assert!(!source.is_single_use());
+    }
+
+    #[test]
+    fn test_day_file_path() {
+        let root = Path::new("missing_inputs");
+
+        let path = root.join("2024").join("day3").join("input.txt");
+
+        assert_eq!(day_file_path(root, 2024, 3, "input.txt"), path);
+        assert_eq!(
+            InputSource::Root(root.to_path_buf()).describe(2024, 3),
+            path.display().to_string()
+        );
+        assert_eq!(
+            InputSource::Root(root.to_path_buf()).answers_path(2024),
+            Some(root.join("2024").join("answers.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_input_names_path() {
+        let root = Path::new("missing_inputs");
+        let path = root.join("2024").join("day1").join("input.txt");
+
+        assert_eq!(day_file_path(root, 2024, 1, "input.txt"), path);
+
+        let source = InputSource::Root(root.to_path_buf());
+
+        assert_eq!(
+            source.read(2024, 1).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        assert_eq!(source.describe(2024, 1), path.display().to_string());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::File(PathBuf::from("missing_inputs/input.txt"));
+
+        assert!(source.read(2024, 1).is_err());
+        assert!(source.is_single_use());
+    }
+}
@@ -0,0 +1,385 @@
+use std::fmt;
+
+/// A JSON value, just enough to write machine-readable output without pulling in a dependency.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    /// Fields are kept in insertion order so output is stable.
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn object<K, I>(fields: I) -> Self
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = (K, Value)>,
+    {
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields
+                .iter()
+                .find(|(field, _)| field == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Value::Number(value) if *value >= 0.0 && value.fract() == 0.0 => Some(*value as usize),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::Number(value as f64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Number(value) if value.is_finite() => write!(f, "{value}"),
+            Value::Number(_) => write!(f, "null"),
+            Value::String(value) => write_string(f, value),
+            Value::Array(values) => {
+                write!(f, "[")?;
+
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{value}")?;
+                }
+
+                write!(f, "]")
+            }
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+/// Parses a single JSON value, which may be surrounded by whitespace.
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser {
+        chars: text.char_indices().peekable(),
+    };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+
+    match parser.chars.next() {
+        None => Ok(value),
+        Some((idx, c)) => Err(format!("unexpected '{c}' at offset {idx}")),
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((idx, c)) => Err(format!(
+                "expected '{expected}' at offset {idx}, found '{c}'"
+            )),
+            None => Err(format!("expected '{expected}', found the end of the input")),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+
+        let Some(&(idx, c)) = self.chars.peek() else {
+            return Err("unexpected end of the input".to_string());
+        };
+
+        match c {
+            'n' => self.keyword("null", Value::Null),
+            't' => self.keyword("true", Value::Bool(true)),
+            'f' => self.keyword("false", Value::Bool(false)),
+            '"' => self.string().map(Value::String),
+            '[' => self.array(),
+            '{' => self.object(),
+            '-' | '0'..='9' => self.number(),
+            _ => Err(format!("unexpected '{c}' at offset {idx}")),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Value) -> Result<Value, String> {
+        for expected in keyword.chars() {
+            self.expect(expected)?;
+        }
+
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let mut number = String::new();
+
+        while let Some((_, c)) = self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            number.push(c);
+        }
+
+        number
+            .parse::<f64>()
+            .map(Value::Number)
+            .map_err(|_| format!("invalid number '{number}'"))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut value = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(value),
+                Some((_, '\\')) => {
+                    let escaped = match self.chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\\')) => '\\',
+                        Some((_, '/')) => '/',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'u')) => self.unicode_escape()?,
+                        Some((idx, c)) => {
+                            return Err(format!("invalid escape '\\{c}' at offset {idx}"))
+                        }
+                        None => break,
+                    };
+
+                    value.push(escaped);
+                }
+                Some((_, c)) => value.push(c),
+                None => break,
+            }
+        }
+
+        Err("unterminated string".to_string())
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            let digit = self
+                .chars
+                .next()
+                .and_then(|(_, c)| c.to_digit(16))
+                .ok_or("invalid unicode escape")?;
+
+            code = code * 16 + digit;
+        }
+
+        char::from_u32(code).ok_or_else(|| format!("invalid unicode escape {code:04x}"))
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+
+        let mut values = vec![];
+
+        if self.chars.next_if(|(_, c)| *c == ']').is_some() {
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+
+            if self.chars.next_if(|(_, c)| *c == ',').is_none() {
+                self.expect(']')?;
+
+                return Ok(Value::Array(values));
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.expect('{')?;
+        self.skip_whitespace();
+
+        let mut fields = vec![];
+
+        if self.chars.next_if(|(_, c)| *c == '}').is_some() {
+            return Ok(Value::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+
+            if self.chars.next_if(|(_, c)| *c == ',').is_none() {
+                self.expect('}')?;
+
+                return Ok(Value::Object(fields));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let value = Value::object([
+            ("day", Value::from(3)),
+            ("answer", Value::from("a \"quoted\"\nline")),
+            ("error", Value::from(None::<String>)),
+            ("parts", Value::Array(vec![true.into(), Value::Number(1.5)])),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"day":3,"answer":"a \"quoted\"\nline","error":null,"parts":[true,1.5]}"#
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let value = parse(
+            r#" {"day": 3, "answer": "a \"quoted\"\u0041", "runtime": null,
+            "parts": [1, -2.5e1, false], "nested": {}} "#,
+        )
+        .unwrap();
+
+        assert_eq!(value.get("day").and_then(Value::as_usize), Some(3));
+        assert_eq!(
+            value.get("answer").and_then(Value::as_str),
+            Some("a \"quoted\"A")
+        );
+        assert!(value.get("runtime").unwrap().is_null());
+        assert_eq!(
+            value.get("parts"),
+            Some(&Value::Array(vec![
+                Value::Number(1.0),
+                Value::Number(-25.0),
+                Value::Bool(false)
+            ]))
+        );
+        assert_eq!(value.get("nested"), Some(&Value::Object(vec![])));
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let value = Value::object([
+            ("text", Value::from("tab\tand \u{1} control")),
+            ("list", Value::Array(vec![Value::Null, Value::from(42)])),
+        ]);
+
+        assert_eq!(parse(&value.to_string()), Ok(value));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("{\"a\" 1}").is_err());
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("\"unterminated").is_err());
+        assert!(parse("nul").is_err());
+        assert!(parse("1 2").is_err());
+    }
+}
@@ -1,38 +1,73 @@
-use std::fs;
 use std::time::Duration;
 use std::time::Instant;
 
-fn instrument<F, T>(f: F, data: &str) -> (T, Duration)
-where
-    F: Fn(&str) -> T,
-{
-    let now = Instant::now();
-    let result = f(data);
+pub mod alloc;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+#[cfg(feature = "http")]
+pub mod client;
+pub mod direction;
+pub mod error;
+pub mod examples;
+#[cfg(feature = "http")]
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod named_inputs;
+pub mod neighbors;
+pub mod notes;
+pub mod parallel;
+pub mod point;
+pub mod report;
+pub mod search;
+pub mod solution;
+pub mod solve_log;
+pub mod submit;
+pub mod watch;
 
-    (result, now.elapsed())
-}
+pub use bench::BenchOptions;
+pub use error::{lines, Line, PuzzleError, PuzzleResult};
+pub use input::InputSource;
+pub use report::{DayReport, OutputFormat, PartReport, Status, Timing};
+pub use solution::{cancelled, DynSolution, Registry, Solution};
 
-pub fn read_file_data(day: usize, file_name: &str) -> String
-{
-    let file_path = format!("test_files/day{day}/{file_name}");
+/// Options that control how a single day's puzzle is run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Only run this part (1 or 2) when set, otherwise run both.
+    pub part: Option<usize>,
+    pub input: InputSource,
+    /// Benchmark each step instead of timing a single run.
+    pub bench: Option<BenchOptions>,
+    /// Give up on parsing or a part that runs for longer than this, reporting it as timed out.
+    pub timeout: Option<Duration>,
+}
 
-    fs::read_to_string(file_path).unwrap()
+impl RunOptions {
+    fn includes_part(&self, part: usize) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
 }
 
-pub fn run_puzzle<A, B, F, G>(day: usize, f1: F, f2: G)
+fn instrument<F, T>(f: F) -> (T, Duration)
 where
-    F: Fn(&str) -> A,
-    G: Fn(&str) -> B,
-    A: std::fmt::Display,
-    B: std::fmt::Display,
+    F: FnOnce() -> T,
 {
-    let contents = read_file_data(day, "input.txt");
+    let now = Instant::now();
+    let result = f();
+
+    (result, now.elapsed())
+}
 
-    let part_1 = instrument(f1, &contents);
-    let part_2 = instrument(f2, &contents);
+/// Reads `{year}/day{day}/{file_name}` from the inputs root. Examples fall back to the committed
+/// test files; the puzzle input does not.
+pub fn read_file_data(year: usize, day: usize, file_name: &str) -> PuzzleResult<String> {
+    let file_path = input::day_file_path(&input::inputs_root(), year, day, file_name);
+    let source = InputSource::File(file_path);
 
-    println!(
-        "[Day {day}]: part 1: {} ({:?}), part 2: {} ({:?})",
-        part_1.0, part_1.1, part_2.0, part_2.1
-    );
+    source
+        .read(year, day)
+        .map_err(|error| PuzzleError::io(error).with_context(day, &source.describe(year, day)))
 }
@@ -0,0 +1,174 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::answers::Verdict;
+use crate::input;
+use crate::DayReport;
+
+/// The directory under a day's inputs with everyone's inputs, like `2024/day7/inputs/alice.txt`.
+pub const NAMED_INPUTS_DIR: &str = "inputs";
+
+/// The extension of the answers file next to a named input, like `alice.answers`, which is in
+/// the format of the answers file.
+pub const ANSWERS_EXTENSION: &str = "answers";
+
+/// One of the inputs of a day, named after the file it's in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl NamedInput {
+    /// The answers file with the expected answers for this input.
+    pub fn answers_path(&self) -> PathBuf {
+        self.path.with_extension(ANSWERS_EXTENSION)
+    }
+}
+
+/// The `.txt` files in `<root>/{year}/day{day}/inputs`, sorted by name. A day without the
+/// directory has no named inputs.
+pub fn named_inputs(root: &Path, year: usize, day: usize) -> io::Result<Vec<NamedInput>> {
+    let dir = input::day_dir(root, year, day).join(NAMED_INPUTS_DIR);
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error),
+    };
+
+    let mut inputs = vec![];
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+
+            inputs.push(NamedInput {
+                name: name.to_string(),
+                path,
+            });
+        }
+    }
+
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(inputs)
+}
+
+/// A table with a row for each input and part, with its answer, time and how it compares to
+/// the expected answer.
+pub fn render_table(reports: &[(String, DayReport)]) -> String {
+    let mut rows = vec![[
+        "input".to_string(),
+        "part".to_string(),
+        "answer".to_string(),
+        "time".to_string(),
+        "check".to_string(),
+    ]];
+
+    for (name, report) in reports {
+        for part in &report.parts {
+            let answer = match &part.answer {
+                Ok(answer) => answer.clone(),
+                Err(error) => format!("error: {error}"),
+            };
+            let time = part
+                .timing
+                .map(|timing| format!("{:?}", timing.duration()))
+                .unwrap_or_default();
+            let check = match &part.verdict {
+                Some(Verdict::Fail { expected }) => format!("FAIL, expected {expected}"),
+                Some(verdict) => verdict.to_string(),
+                None => String::new(),
+            };
+
+            rows.push([name.clone(), part.part.to_string(), answer, time, check]);
+        }
+    }
+
+    let widths = (0..5)
+        .map(|column| rows.iter().map(|row| row[column].chars().count()).max())
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+
+    rows.iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>();
+
+            format!("  {}\n", cells.join("  ").trim_end())
+        })
+        .collect()
+}
+
+/// The total time spent solving, over every input.
+pub fn total_time(reports: &[(String, DayReport)]) -> Duration {
+    reports.iter().map(|(_, report)| report.task_time()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PartReport, Status, Timing};
+
+    fn report(answers: [(&str, Option<Verdict>); 2]) -> DayReport {
+        DayReport {
+            year: 2024,
+            day: 7,
+            input: "2024/day7/inputs/alice.txt".to_string(),
+            parse: Some(Timing::Single(Duration::from_micros(3))),
+            parts: answers
+                .into_iter()
+                .zip(1..)
+                .map(|((answer, verdict), part)| PartReport {
+                    part,
+                    status: Status::Ok,
+                    answer: Ok(answer.to_string()),
+                    timing: Some(Timing::Single(Duration::from_micros(part as u64))),
+                    memory: None,
+                    verdict,
+                })
+                .collect(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let reports = [
+            (
+                "alice".to_string(),
+                report([
+                    ("3749", Some(Verdict::Pass)),
+                    (
+                        "11387",
+                        Some(Verdict::Fail {
+                            expected: "11388".to_string(),
+                        }),
+                    ),
+                ]),
+            ),
+            (
+                "bob".to_string(),
+                report([("1", Some(Verdict::Unknown)), ("2", None)]),
+            ),
+        ];
+
+        assert_eq!(
+            render_table(&reports),
+            "  input  part  answer  time  check\n  \
+             alice  1     3749    1µs   pass\n  \
+             alice  2     11387   2µs   FAIL, expected 11388\n  \
+             bob    1     1       1µs   unknown\n  \
+             bob    2     2       2µs\n"
+        );
+        assert_eq!(total_time(&reports), Duration::from_micros(12));
+    }
+}
@@ -0,0 +1,132 @@
+use std::array;
+
+use crate::direction::Direction8;
+use crate::point::Point;
+
+/// The points next to a point inside a `width` by `height` area, clockwise from the one above.
+/// Points past the edges are skipped, unless the area wraps around like a torus.
+#[derive(Debug, Clone)]
+pub struct Neighbors {
+    center: Point,
+    width: usize,
+    height: usize,
+    diagonals: bool,
+    wrapping: bool,
+    directions: array::IntoIter<Direction8, 8>,
+}
+
+impl Neighbors {
+    /// The up to four points a straight step away.
+    pub fn four(center: Point, width: usize, height: usize) -> Self {
+        Self::new(center, width, height, false)
+    }
+
+    /// The up to eight points a straight or diagonal step away.
+    pub fn eight(center: Point, width: usize, height: usize) -> Self {
+        Self::new(center, width, height, true)
+    }
+
+    fn new(center: Point, width: usize, height: usize, diagonals: bool) -> Self {
+        Self {
+            center,
+            width,
+            height,
+            diagonals,
+            wrapping: false,
+            directions: Direction8::ALL.into_iter(),
+        }
+    }
+
+    /// Steps past an edge come back in on the opposite edge, so there is always a neighbor in
+    /// every direction.
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+}
+
+impl Iterator for Neighbors {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        // An empty area has no points to be next to, even when wrapping.
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+
+        for direction in self.directions.by_ref() {
+            if direction.is_diagonal() && !self.diagonals {
+                continue;
+            }
+
+            let offset = direction.offset();
+
+            if self.wrapping {
+                return Some(self.center.wrapping_add(offset, self.width, self.height));
+            }
+
+            if let Some(point) = self.center + offset {
+                if point.row < self.height && point.col < self.width {
+                    return Some(point);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_four() {
+        let neighbors = Neighbors::four(Point::new(1, 1), 3, 3).collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            [
+                Point::new(0, 1),
+                Point::new(1, 2),
+                Point::new(2, 1),
+                Point::new(1, 0)
+            ]
+        );
+
+        let corner = Neighbors::four(Point::new(0, 2), 3, 3).collect::<Vec<_>>();
+        assert_eq!(corner, [Point::new(1, 2), Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_eight() {
+        assert_eq!(Neighbors::eight(Point::new(1, 1), 3, 3).count(), 8);
+        assert_eq!(Neighbors::eight(Point::new(0, 0), 3, 3).count(), 3);
+        assert_eq!(Neighbors::eight(Point::new(2, 1), 3, 3).count(), 5);
+        assert_eq!(Neighbors::eight(Point::new(0, 0), 1, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let neighbors = Neighbors::four(Point::new(0, 0), 4, 3)
+            .wrapping()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            [
+                Point::new(2, 0),
+                Point::new(0, 1),
+                Point::new(1, 0),
+                Point::new(0, 3)
+            ]
+        );
+
+        assert_eq!(
+            Neighbors::eight(Point::new(0, 0), 4, 3).wrapping().count(),
+            8
+        );
+        assert_eq!(
+            Neighbors::eight(Point::new(0, 0), 0, 0).wrapping().count(),
+            0
+        );
+    }
+}
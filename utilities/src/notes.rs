@@ -0,0 +1,375 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::solve_log::{LogEntry, Timestamp};
+
+/// Something in the notes that couldn't be read as written, or doesn't add up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotesIssue {
+    /// 1-based line number in the notes.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for NotesIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parses the hand-written `notes.txt` for `year` into solve log entries. Anything that had to be
+/// guessed at or left out is reported as an issue instead of failing the whole import.
+///
+/// ```text
+/// Day 1:
+///     - Started 2024-12-1 7:16pm
+///     - part1: 2024-12-1 7:26pm [2176849, 1.5797ms] (105675), an optional note that
+///       can continue on the next lines
+/// ```
+pub fn parse_notes(contents: &str, year: usize) -> (Vec<LogEntry>, Vec<NotesIssue>) {
+    let mut entries: Vec<LogEntry> = vec![];
+    let mut issues = vec![];
+    let mut day = None;
+    // Whether the previous line was an item that continuation lines belong to.
+    let mut continues = false;
+
+    for (idx, line) in contents.lines().enumerate() {
+        let number = idx + 1;
+        let mut issue = |message: String| {
+            issues.push(NotesIssue {
+                line: number,
+                message,
+            })
+        };
+
+        let trimmed = line.trim();
+
+        if let Some(value) = trimmed
+            .strip_prefix("Day ")
+            .and_then(|rest| rest.strip_suffix(':'))
+        {
+            day = value.parse::<usize>().ok();
+            continues = false;
+
+            if day.is_none() {
+                issue(format!("invalid day '{value}'"));
+            }
+
+            continue;
+        }
+
+        let Some(day) = day else {
+            continue;
+        };
+
+        if let Some(item) = trimmed.strip_prefix("- ").or(trimmed.strip_prefix('-')) {
+            let entry = parse_item(year, day, item.trim(), &mut issue);
+            continues = entry.is_some();
+            entries.extend(entry);
+        } else if trimmed.is_empty() {
+            continues = false;
+        } else if continues && line.starts_with(char::is_whitespace) {
+            let entry = entries.last_mut().unwrap();
+            let note = entry.note.get_or_insert_with(String::new);
+
+            if !note.is_empty() {
+                note.push(' ');
+            }
+
+            note.push_str(trimmed);
+        } else {
+            issue(format!("ignored '{trimmed}'"));
+        }
+    }
+
+    check_order(&entries, contents, &mut issues);
+
+    (entries, issues)
+}
+
+fn parse_item(
+    year: usize,
+    day: usize,
+    item: &str,
+    issue: &mut impl FnMut(String),
+) -> Option<LogEntry> {
+    if let Some(time) = item.strip_prefix("Started").or(item.strip_prefix("Start")) {
+        let time = time.trim();
+
+        if time.is_empty() {
+            return None;
+        }
+
+        return Some(LogEntry {
+            year,
+            day,
+            time: parse_time(time, issue),
+            ..LogEntry::default()
+        });
+    }
+
+    let Some((part, rest)) = item
+        .strip_prefix("part")
+        .and_then(|rest| rest.split_once(':'))
+        .and_then(|(part, rest)| Some((part.parse::<usize>().ok()?, rest.trim())))
+    else {
+        return Some(LogEntry {
+            year,
+            day,
+            note: Some(item.to_string()),
+            ..LogEntry::default()
+        });
+    };
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut entry = LogEntry {
+        year,
+        day,
+        part: Some(part),
+        ..LogEntry::default()
+    };
+
+    let (time, rest) = rest.split_once('[').unwrap_or((rest, ""));
+    entry.time = parse_time(time.trim(), issue);
+
+    let (result, rest) = rest.split_once(']').unwrap_or((rest, ""));
+    let (answer, runtime) = match result.rsplit_once(',') {
+        Some((answer, runtime)) => (answer.trim(), Some(runtime.trim())),
+        None => (result.trim(), None),
+    };
+
+    if answer.is_empty() {
+        issue(format!("part {part} has no answer"));
+    } else {
+        entry.answer = Some(answer.to_string());
+    }
+
+    entry.runtime = runtime.and_then(|runtime| {
+        let parsed = parse_runtime(runtime);
+
+        if parsed.is_none() {
+            issue(format!("unrecognized runtime '{runtime}' for part {part}"));
+        }
+
+        parsed
+    });
+
+    let mut rest = rest.trim();
+
+    if let Some((rank, after)) = rest.strip_prefix('(').and_then(|rest| rest.split_once(')')) {
+        match rank.trim().parse::<usize>() {
+            Ok(rank) => entry.rank = Some(rank),
+            Err(_) => issue(format!("invalid rank '{rank}' for part {part}")),
+        }
+
+        rest = after.trim();
+    }
+
+    let note = rest.trim_start_matches(',').trim();
+
+    if !note.is_empty() {
+        entry.note = Some(note.to_string());
+    }
+
+    Some(entry)
+}
+
+/// Parses times like `2024-12-1 7:16pm` or `2024-12-5 11pm`. Times without am or pm are taken as
+/// 24-hour times.
+fn parse_time(text: &str, issue: &mut impl FnMut(String)) -> Option<Timestamp> {
+    let mut words = text.split_ascii_whitespace();
+    let date = words.next()?;
+
+    let [year, month, day] = date
+        .split('-')
+        .map(|value| value.parse::<u32>().ok())
+        .collect::<Vec<_>>()[..]
+    else {
+        issue(format!("invalid date '{date}'"));
+        return None;
+    };
+
+    let time = words.next().and_then(|time| {
+        let (clock, offset) = if let Some(clock) = time.strip_suffix("am") {
+            (clock, Some(0))
+        } else if let Some(clock) = time.strip_suffix("pm") {
+            (clock, Some(12))
+        } else {
+            (time, None)
+        };
+
+        let (hour, minute) = clock.split_once(':').unwrap_or((clock, "0"));
+        let (Ok(hour), Ok(minute)) = (hour.parse::<u32>(), minute.parse::<u32>()) else {
+            issue(format!("invalid time '{time}'"));
+            return None;
+        };
+
+        match offset {
+            Some(offset) => Some((hour % 12 + offset, minute)),
+            None => {
+                issue(format!(
+                    "time '{time}' has no am or pm, read as a 24-hour time"
+                ));
+                Some((hour, minute))
+            }
+        }
+    });
+
+    let extra = words.collect::<Vec<_>>();
+
+    if !extra.is_empty() {
+        issue(format!("ignored '{}' after the time", extra.join(" ")));
+    }
+
+    let timestamp = Timestamp {
+        year: year?,
+        month: month?,
+        day: day?,
+        time,
+    };
+
+    if timestamp.is_valid() {
+        Some(timestamp)
+    } else {
+        issue(format!("invalid date '{date}'"));
+        None
+    }
+}
+
+/// Parses runtimes like `531.7us`, `1.5797ms` or `4.649s`.
+fn parse_runtime(text: &str) -> Option<Duration> {
+    let split = text.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let (value, unit) = text.split_at(split);
+    let value = value.parse::<f64>().ok()?;
+
+    let nanos_per_unit = match unit.trim() {
+        "ns" => 1.0,
+        "us" | "µs" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+
+    Some(Duration::from_nanos((value * nanos_per_unit).round() as u64))
+}
+
+/// Flags parts that were finished before their day was started.
+fn check_order(entries: &[LogEntry], contents: &str, issues: &mut Vec<NotesIssue>) {
+    for entry in entries {
+        let (Some(part), Some(finished)) = (entry.part, entry.time) else {
+            continue;
+        };
+
+        let started = entries
+            .iter()
+            .find(|other| other.day == entry.day && other.part.is_none() && other.time.is_some())
+            .and_then(|other| other.time);
+
+        if let Some(started) = started.filter(|started| finished < *started) {
+            issues.push(NotesIssue {
+                line: find_line(contents, entry.day, part),
+                message: format!(
+                    "day {} part {part} finished at {finished}, before the day was started at \
+                     {started}",
+                    entry.day
+                ),
+            });
+        }
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+}
+
+fn find_line(contents: &str, day: usize, part: usize) -> usize {
+    let header = format!("Day {day}:");
+    let item = format!("- part{part}:");
+
+    contents
+        .lines()
+        .enumerate()
+        .skip_while(|(_, line)| line.trim() != header)
+        .find(|(_, line)| line.trim().starts_with(&item))
+        .map_or(0, |(idx, _)| idx + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "\
+https://adventofcode.com/
+
+Day 5:
+    - Start 2024-12-6 7:55pm
+    - part1: 2024-12-6 8:58pm [7307, 11.114ms] (81540)
+    - part2: 2024-12-10 5:52pm [4713, 16.985m] (86749), took a while,
+      but got there.
+    - Made improvements after reading on reddit
+
+Day 13:
+    - Start 2024-12-27 5:54pm
+    - part1: 2024-12-17 6:33pm [7,5,4, 2.08ms] (49367)
+    - part2:
+
+Day 22:
+    - Start 2025-1-6 9:02pm ish
+    - part1: 2025-1-17 10:32 [2018, 756.97ms ] (23564)
+";
+
+    #[test]
+    fn test_parse_notes() {
+        let (entries, _) = parse_notes(NOTES, 2024);
+
+        assert_eq!(entries.len(), 8);
+        assert_eq!(
+            entries[1],
+            LogEntry {
+                year: 2024,
+                day: 5,
+                part: Some(1),
+                time: "2024-12-06T20:58".parse().ok(),
+                answer: Some("7307".to_string()),
+                runtime: Some(Duration::from_micros(11114)),
+                rank: Some(81540),
+                note: None,
+            }
+        );
+        assert_eq!(entries[2].runtime, None);
+        assert_eq!(
+            entries[2].note.as_deref(),
+            Some("took a while, but got there.")
+        );
+        assert_eq!(
+            entries[3].note.as_deref(),
+            Some("Made improvements after reading on reddit")
+        );
+        assert_eq!(entries[5].answer.as_deref(), Some("7,5,4"));
+        assert_eq!(entries[7].time, "2025-01-17T10:32".parse().ok());
+        assert_eq!(entries[7].runtime, Some(Duration::from_micros(756970)));
+    }
+
+    #[test]
+    fn test_notes_issues() {
+        let (_, issues) = parse_notes(NOTES, 2024);
+        let lines = issues.iter().map(|issue| issue.line).collect::<Vec<_>>();
+
+        assert_eq!(lines, [6, 12, 16, 17]);
+        assert!(issues[0].message.contains("16.985m"));
+        assert!(issues[1].message.contains("before the day was started"));
+        assert!(issues[2].message.contains("ish"));
+        assert!(issues[3].message.contains("no am or pm"));
+    }
+
+    #[test]
+    fn test_parse_runtime() {
+        assert_eq!(
+            parse_runtime("531.7us"),
+            Some(Duration::from_nanos(531_700))
+        );
+        assert_eq!(parse_runtime("4.649s"), Some(Duration::from_millis(4649)));
+        assert_eq!(parse_runtime("16.985m"), None);
+    }
+}
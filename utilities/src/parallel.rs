@@ -0,0 +1,228 @@
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+use crate::solution::{finish_day, run_part, selected_parts, start_day, ParsedInput};
+use crate::{DayReport, DynSolution, PartReport, PuzzleError, RunOptions};
+
+enum Task {
+    Parse(usize),
+    Part(usize, usize, ParsedInput),
+}
+
+enum Done {
+    Parsed(usize, DayReport, Option<ParsedInput>),
+    Part(usize, PartReport, Option<PuzzleError>),
+}
+
+/// A day that has been parsed, waiting for its parts.
+struct Pending {
+    report: DayReport,
+    parts: Vec<(PartReport, Option<PuzzleError>)>,
+    remaining: usize,
+}
+
+/// Runs the days on `jobs` threads, parsing each day and then running its parts as separate
+/// tasks. Reports are handed to `on_report` in the order of `solutions` as soon as every earlier
+/// day is done, so the output doesn't depend on which tasks finish first.
+pub fn run_parallel<F>(
+    solutions: &[&'static dyn DynSolution],
+    options: &RunOptions,
+    jobs: usize,
+    mut on_report: F,
+) where
+    F: FnMut(DayReport),
+{
+    let (task_sender, task_receiver) = mpsc::channel();
+    let (done_sender, done_receiver) = mpsc::channel();
+    let task_receiver = Mutex::new(task_receiver);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, solutions.len().max(1) * 2) {
+            let task_receiver = &task_receiver;
+            let done_sender = done_sender.clone();
+
+            scope.spawn(move || loop {
+                let task = task_receiver.lock().unwrap().recv();
+
+                let Ok(task) = task else {
+                    // Every task has been handed out.
+                    break;
+                };
+
+                let done = match task {
+                    Task::Parse(idx) => {
+                        let (report, parsed) = start_day(solutions[idx], options);
+                        Done::Parsed(idx, report, parsed)
+                    }
+                    Task::Part(idx, part, parsed) => {
+                        let (report, error) = run_part(solutions[idx], options, part, &parsed);
+                        Done::Part(idx, report, error)
+                    }
+                };
+
+                if done_sender.send(done).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(done_sender);
+
+        for idx in 0..solutions.len() {
+            task_sender.send(Task::Parse(idx)).unwrap();
+        }
+
+        let mut pending = solutions.iter().map(|_| None).collect::<Vec<_>>();
+        let mut finished = solutions.iter().map(|_| None).collect::<Vec<_>>();
+        let mut next = 0;
+
+        while next < solutions.len() {
+            match done_receiver.recv().unwrap() {
+                Done::Parsed(idx, report, None) => finished[idx] = Some(report),
+                Done::Parsed(idx, report, Some(parsed)) => {
+                    let parts = selected_parts(options);
+
+                    for part in &parts {
+                        task_sender
+                            .send(Task::Part(idx, *part, parsed.clone()))
+                            .unwrap();
+                    }
+
+                    pending[idx] = Some(Pending {
+                        report,
+                        parts: vec![],
+                        remaining: parts.len(),
+                    });
+                }
+                Done::Part(idx, part, error) => {
+                    let day = pending[idx].as_mut().unwrap();
+                    day.parts.push((part, error));
+                    day.remaining -= 1;
+
+                    if day.remaining == 0 {
+                        let Pending {
+                            mut report, parts, ..
+                        } = pending[idx].take().unwrap();
+
+                        finish_day(&mut report, parts);
+                        finished[idx] = Some(report);
+                    }
+                }
+            }
+
+            while let Some(report) = finished.get_mut(next).and_then(Option::take) {
+                on_report(report);
+                next += 1;
+            }
+        }
+
+        // Lets the workers see that there are no more tasks.
+        drop(task_sender);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InputSource, PuzzleResult, Solution};
+    use std::time::Duration;
+
+    /// Sleeps for longer the lower its day is, so later days finish first.
+    struct Slow<const DAY: usize>;
+
+    impl<const DAY: usize> Solution for Slow<DAY> {
+        const YEAR: usize = 2024;
+        const DAY: usize = DAY;
+
+        type Input = usize;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(contents: &str) -> PuzzleResult<usize> {
+            thread::sleep(Duration::from_millis(40 / DAY as u64));
+
+            contents
+                .trim()
+                .parse()
+                .map_err(|_| PuzzleError::parse("not a number"))
+        }
+
+        fn part_1(input: &usize) -> PuzzleResult<usize> {
+            Ok(input * DAY)
+        }
+
+        fn part_2(input: &usize) -> PuzzleResult<usize> {
+            match DAY {
+                3 => Err(PuzzleError::solve("no part 2")),
+                5 => panic!("part 2 of day 5 panicked"),
+                _ => Ok(input + DAY),
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_parallel_in_order() {
+        let solutions: [&'static dyn DynSolution; 4] =
+            [&Slow::<1>, &Slow::<2>, &Slow::<3>, &Slow::<4>];
+        let options = RunOptions {
+            input: InputSource::Inline("10".to_string()),
+            ..RunOptions::default()
+        };
+
+        let mut reports = vec![];
+        run_parallel(&solutions, &options, 4, |report| reports.push(report));
+
+        let days = reports.iter().map(|report| report.day).collect::<Vec<_>>();
+        assert_eq!(days, [1, 2, 3, 4]);
+
+        for (report, solution) in reports.iter().zip(solutions) {
+            let sequential = solution.run(&options);
+            let answers = |report: &DayReport| {
+                report
+                    .parts
+                    .iter()
+                    .map(|part| (part.part, part.answer.clone()))
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(answers(report), answers(&sequential));
+            assert_eq!(report.is_ok(), sequential.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_run_parallel_parse_errors() {
+        let solutions: [&'static dyn DynSolution; 2] = [&Slow::<1>, &Slow::<2>];
+        let options = RunOptions {
+            part: Some(2),
+            input: InputSource::Inline("ten".to_string()),
+            ..RunOptions::default()
+        };
+
+        let mut reports = vec![];
+        run_parallel(&solutions, &options, 2, |report| reports.push(report));
+
+        assert_eq!(reports.len(), 2);
+        assert!(reports
+            .iter()
+            .all(|report| report.parts.len() == 1 && !report.is_ok()));
+    }
+
+    #[test]
+    fn test_run_parallel_panics() {
+        let solutions: [&'static dyn DynSolution; 2] = [&Slow::<5>, &Slow::<6>];
+        let options = RunOptions {
+            input: InputSource::Inline("10".to_string()),
+            ..RunOptions::default()
+        };
+
+        let mut reports = vec![];
+        run_parallel(&solutions, &options, 2, |report| reports.push(report));
+
+        assert_eq!(
+            reports[0].parts[1].answer,
+            Err("day 5: <inline>: PANIC: part 2 of day 5 panicked".to_string())
+        );
+        assert!(reports[1].is_ok());
+    }
+}
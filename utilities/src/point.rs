@@ -0,0 +1,178 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A position in a grid, counted from the top left.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The point for a puzzle's `x,y` position, where `x` is the column and `y` is the row.
+    pub fn from_xy(x: usize, y: usize) -> Self {
+        Self::new(y, x)
+    }
+
+    /// The column, which puzzles call `x`.
+    pub fn x(&self) -> usize {
+        self.col
+    }
+
+    /// The row, which puzzles call `y`.
+    pub fn y(&self) -> usize {
+        self.row
+    }
+
+    /// The number of straight steps between the points.
+    pub fn manhattan(&self, other: &Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The number of steps between the points when diagonal steps are allowed.
+    pub fn chebyshev(&self, other: &Point) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+
+    /// The point `vector` away, wrapping around the edges of a `width` by `height` area.
+    pub fn wrapping_add(self, vector: Vector, width: usize, height: usize) -> Point {
+        let wrap = |value: usize, offset: isize, max: usize| {
+            (value as isize + offset).rem_euclid(max as isize) as usize
+        };
+
+        Point::new(
+            wrap(self.row, vector.row, height),
+            wrap(self.col, vector.col, width),
+        )
+    }
+}
+
+/// The point `vector` away, which is `None` when it would be above or left of the origin. Use
+/// [`Grid::offset`](crate::grid::Grid::offset) to also check the other edges.
+impl Add<Vector> for Point {
+    type Output = Option<Point>;
+
+    fn add(self, vector: Vector) -> Option<Point> {
+        Some(Point::new(
+            self.row.checked_add_signed(vector.row)?,
+            self.col.checked_add_signed(vector.col)?,
+        ))
+    }
+}
+
+/// The offset that takes `other` to `self`.
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(
+            self.row as isize - other.row as isize,
+            self.col as isize - other.col as isize,
+        )
+    }
+}
+
+/// An offset between two points, which can point in any direction.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+pub struct Vector {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Vector {
+    pub fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// The offset for a puzzle's `x,y` offset, where `x` is across the columns and `y` is down
+    /// the rows.
+    pub fn from_xy(x: isize, y: isize) -> Self {
+        Self::new(y, x)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, times: isize) -> Vector {
+        Vector::new(self.row * times, self.col * times)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point::new(2, 3);
+
+        assert_eq!(point + Vector::new(-2, 1), Some(Point::new(0, 4)));
+        assert_eq!(point + Vector::new(0, -4), None);
+        assert_eq!(Point::new(5, 1) - point, Vector::new(3, -2));
+        assert_eq!(-Vector::new(3, -2) * 2, Vector::new(-6, 4));
+        assert_eq!(Vector::new(1, 1) + Vector::new(2, -1), Vector::new(3, 0));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 7);
+        let b = Point::new(4, 3);
+
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(b.manhattan(&a), 7);
+    }
+
+    #[test]
+    fn test_xy() {
+        let point = Point::from_xy(6, 1);
+
+        assert_eq!(point, Point::new(1, 6));
+        assert_eq!((point.x(), point.y()), (6, 1));
+        assert_eq!(Vector::from_xy(3, -3), Vector::new(-3, 3));
+    }
+
+    #[test]
+    fn test_wrapping_add() {
+        let point = Point::from_xy(2, 4);
+
+        assert_eq!(
+            point.wrapping_add(Vector::from_xy(2, -3), 11, 7),
+            Point::from_xy(4, 1)
+        );
+        assert_eq!(
+            point.wrapping_add(Vector::from_xy(2, -3) * 5, 11, 7),
+            Point::from_xy(1, 3)
+        );
+    }
+}
@@ -0,0 +1,443 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::alloc::AllocStats;
+use crate::answers::{Answers, Verdict};
+use crate::bench::Stats;
+use crate::error::ErrorKind;
+use crate::json::Value;
+use crate::PuzzleError;
+
+/// How long a step took: a single timed run, or statistics over several when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Timing {
+    Single(Duration),
+    Bench(Stats),
+}
+
+impl Timing {
+    /// The representative duration, which is the median when benchmarking.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Timing::Single(duration) => *duration,
+            Timing::Bench(stats) => stats.median,
+        }
+    }
+}
+
+/// How a part ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The input couldn't be read or parsed, or the part returned an error.
+    Error,
+    Timeout,
+    Panic,
+}
+
+impl Status {
+    /// The status of a part that failed with `error`.
+    pub fn of(error: &PuzzleError) -> Self {
+        match error.kind {
+            ErrorKind::Timeout(_) => Status::Timeout,
+            ErrorKind::Panic(_) => Status::Panic,
+            ErrorKind::Io(_) | ErrorKind::Parse(_) | ErrorKind::Solve(_) => Status::Error,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+            Status::Panic => "panic",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: usize,
+    pub status: Status,
+    /// The answer, or the error message that replaced it.
+    pub answer: Result<String, String>,
+    /// Missing when the part failed, or never ran because the input couldn't be read or parsed.
+    pub timing: Option<Timing>,
+    /// The heap use of a single run, only known when the counting allocator is installed.
+    pub memory: Option<AllocStats>,
+    /// How the answer compares to the one on record, when answers are being verified.
+    pub verdict: Option<Verdict>,
+}
+
+/// Everything that happened while running one day.
+#[derive(Debug)]
+pub struct DayReport {
+    pub year: usize,
+    pub day: usize,
+    /// Where the input was read from.
+    pub input: String,
+    /// Missing when the input couldn't be read or parsed, in which case every part failed with
+    /// `error`.
+    pub parse: Option<Timing>,
+    pub parts: Vec<PartReport>,
+    /// The first error, if anything failed.
+    pub error: Option<PuzzleError>,
+}
+
+impl DayReport {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The time spent parsing and solving, added up over the steps that ran.
+    pub fn task_time(&self) -> Duration {
+        self.parse
+            .iter()
+            .chain(self.parts.iter().filter_map(|part| part.timing.as_ref()))
+            .map(Timing::duration)
+            .sum()
+    }
+
+    /// Compares each part's answer to the one on record.
+    pub fn verify(&mut self, answers: &Answers) {
+        for part in &mut self.parts {
+            let answer = part.answer.as_deref().map_err(String::as_str);
+            part.verdict = Some(answers.check(self.day, part.part, answer));
+        }
+    }
+
+    /// Adds the answers of solved parts that have none on record yet, returning how many were
+    /// added. Answers that are already known are left alone, even if they differ.
+    pub fn record(&self, answers: &mut Answers) -> usize {
+        let mut recorded = 0;
+
+        for part in &self.parts {
+            if let (Ok(answer), None) = (&part.answer, answers.get(self.day, part.part)) {
+                answers.insert(self.day, part.part, answer);
+                recorded += 1;
+            }
+        }
+
+        recorded
+    }
+
+    /// The report in the given format, with a trailing newline.
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.to_text(),
+            OutputFormat::Json => self
+                .parts
+                .iter()
+                .map(|part| format!("{}\n", self.to_json(part)))
+                .collect(),
+            OutputFormat::Csv => self
+                .parts
+                .iter()
+                .map(|part| format!("{}\n", self.to_csv(part)))
+                .collect(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let day = self.day;
+
+        let parse = match (&self.parse, &self.error) {
+            (Some(parse), _) => parse,
+            (None, Some(error)) => return format!("[Day {day}]: error: {error}\n"),
+            (None, None) => return format!("[Day {day}]:\n"),
+        };
+
+        let mut results = vec![];
+        let mut timings = vec![];
+
+        match parse {
+            Timing::Single(elapsed) => results.push(format!("parse ({elapsed:?})")),
+            Timing::Bench(stats) => timings.push(format!("    parse:  {stats}")),
+        }
+
+        for report in &self.parts {
+            let part = report.part;
+
+            let result = match (&report.answer, &report.timing) {
+                (Ok(answer), Some(Timing::Single(elapsed))) => match &report.memory {
+                    Some(memory) => format!("part {part}: {answer} ({elapsed:?}, {memory})"),
+                    None => format!("part {part}: {answer} ({elapsed:?})"),
+                },
+                (Ok(answer), timing) => {
+                    if let Some(Timing::Bench(stats)) = timing {
+                        timings.push(format!("    part {part}: {stats}"));
+                    }
+
+                    match &report.memory {
+                        Some(memory) => format!("part {part}: {answer} ({memory})"),
+                        None => format!("part {part}: {answer}"),
+                    }
+                }
+                (Err(error), _) => format!("part {part}: error: {error}"),
+            };
+
+            let verdict = match &report.verdict {
+                Some(Verdict::Fail { expected }) => format!(" [fail, expected {expected}]"),
+                Some(verdict) => format!(" [{verdict}]"),
+                None => String::new(),
+            };
+
+            results.push(result + &verdict);
+        }
+
+        let mut text = format!("[Day {day}]: {}\n", results.join(", "));
+
+        for timing in timings {
+            text.push_str(&timing);
+            text.push('\n');
+        }
+
+        text
+    }
+
+    fn to_json(&self, part: &PartReport) -> Value {
+        let (answer, error) = split_answer(part);
+
+        Value::object([
+            ("year", Value::from(self.year)),
+            ("day", Value::from(self.day)),
+            ("part", Value::from(part.part)),
+            ("status", Value::from(part.status.to_string())),
+            ("answer", Value::from(answer)),
+            ("duration_ns", Value::from(duration_ns(part.timing))),
+            ("parse_ns", Value::from(duration_ns(self.parse))),
+            ("input", Value::from(self.input.as_str())),
+            ("error", Value::from(error)),
+            ("verdict", Value::from(verdict(part))),
+            ("expected", Value::from(expected(part))),
+            ("peak_bytes", Value::from(memory(part, |m| m.peak_bytes))),
+            (
+                "allocated_bytes",
+                Value::from(memory(part, |m| m.allocated_bytes)),
+            ),
+            ("allocations", Value::from(memory(part, |m| m.allocations))),
+        ])
+    }
+
+    fn to_csv(&self, part: &PartReport) -> String {
+        let (answer, error) = split_answer(part);
+        let optional = |value: Option<usize>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            part.part.to_string(),
+            part.status.to_string(),
+            csv_field(answer.unwrap_or_default()),
+            optional(duration_ns(part.timing)),
+            optional(duration_ns(self.parse)),
+            csv_field(&self.input),
+            csv_field(error.unwrap_or_default()),
+            verdict(part).unwrap_or_default(),
+            csv_field(expected(part).unwrap_or_default()),
+            optional(memory(part, |m| m.peak_bytes)),
+            optional(memory(part, |m| m.allocated_bytes)),
+            optional(memory(part, |m| m.allocations)),
+        ]
+        .join(",")
+    }
+}
+
+fn split_answer(part: &PartReport) -> (Option<&str>, Option<&str>) {
+    match &part.answer {
+        Ok(answer) => (Some(answer), None),
+        Err(error) => (None, Some(error)),
+    }
+}
+
+fn verdict(part: &PartReport) -> Option<String> {
+    part.verdict.as_ref().map(Verdict::to_string)
+}
+
+fn expected(part: &PartReport) -> Option<&str> {
+    match &part.verdict {
+        Some(Verdict::Fail { expected }) => Some(expected),
+        _ => None,
+    }
+}
+
+fn memory(part: &PartReport, field: fn(&AllocStats) -> usize) -> Option<usize> {
+    part.memory.as_ref().map(field)
+}
+
+fn duration_ns(timing: Option<Timing>) -> Option<usize> {
+    timing.map(|timing| timing.duration().as_nanos() as usize)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable lines, one per day.
+    #[default]
+    Text,
+    /// One JSON object per line for each day and part.
+    Json,
+    /// One CSV row for each day and part, after a header row.
+    Csv,
+}
+
+impl OutputFormat {
+    /// A line to print before the reports of `year`.
+    pub fn header(&self, year: usize) -> Option<String> {
+        match self {
+            OutputFormat::Text => Some(format!("Advent of code {year}")),
+            OutputFormat::Json => None,
+            OutputFormat::Csv => Some(
+                "year,day,part,status,answer,duration_ns,parse_ns,input,error,verdict,expected,\
+                      peak_bytes,allocated_bytes,allocations"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("Invalid format '{s}', expected text, json or csv")),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> DayReport {
+        DayReport {
+            year: 2024,
+            day: 7,
+            input: "inputs/day7/input.txt".to_string(),
+            parse: Some(Timing::Single(Duration::from_nanos(1500))),
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    status: Status::Ok,
+                    answer: Ok("3749".to_string()),
+                    timing: Some(Timing::Single(Duration::from_nanos(2000))),
+                    memory: None,
+                    verdict: None,
+                },
+                PartReport {
+                    part: 2,
+                    status: Status::Error,
+                    answer: Err("no solution, sorry".to_string()),
+                    timing: Some(Timing::Single(Duration::from_nanos(10))),
+                    memory: None,
+                    verdict: None,
+                },
+            ],
+            error: Some(PuzzleError::solve("no solution, sorry")),
+        }
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!(
+            report().render(OutputFormat::Text),
+            "[Day 7]: parse (1.5µs), part 1: 3749 (2µs), part 2: error: no solution, sorry\n"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = report().render(OutputFormat::Json);
+        let lines = json.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            [
+                r#"{"year":2024,"day":7,"part":1,"status":"ok","answer":"3749","duration_ns":2000,"parse_ns":1500,"input":"inputs/day7/input.txt","error":null,"verdict":null,"expected":null,"peak_bytes":null,"allocated_bytes":null,"allocations":null}"#,
+                r#"{"year":2024,"day":7,"part":2,"status":"error","answer":null,"duration_ns":10,"parse_ns":1500,"input":"inputs/day7/input.txt","error":"no solution, sorry","verdict":null,"expected":null,"peak_bytes":null,"allocated_bytes":null,"allocations":null}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            report().render(OutputFormat::Csv),
+            "2024,7,1,ok,3749,2000,1500,inputs/day7/input.txt,,,,,,\n\
+             2024,7,2,error,,10,1500,inputs/day7/input.txt,\"no solution, sorry\",,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_render_memory() {
+        let mut report = report();
+        report.parts[0].memory = Some(AllocStats {
+            peak_bytes: 4096,
+            allocated_bytes: 10240,
+            allocations: 3,
+        });
+
+        assert!(report.render(OutputFormat::Text).starts_with(
+            "[Day 7]: parse (1.5µs), part 1: 3749 (2µs, peak 4.0 KiB, 10.0 KiB in 3 allocations)"
+        ));
+        assert!(report
+            .render(OutputFormat::Csv)
+            .starts_with("2024,7,1,ok,3749,2000,1500,inputs/day7/input.txt,,,,4096,10240,3\n"));
+    }
+
+    #[test]
+    fn test_verify_and_record() {
+        let mut answers = Answers::parse("7 2 11387\n").unwrap();
+        let mut report = report();
+
+        assert_eq!(report.record(&mut answers), 1);
+        assert_eq!(answers.get(7, 1), Some("3749"));
+        assert_eq!(answers.get(7, 2), Some("11387"));
+
+        report.verify(&Answers::parse("7 2 11387\n").unwrap());
+
+        assert_eq!(
+            report.render(OutputFormat::Text),
+            "[Day 7]: parse (1.5µs), part 1: 3749 (2µs) [unknown], part 2: error: no solution, sorry \
+             [fail, expected 11387]\n"
+        );
+        assert!(report
+            .render(OutputFormat::Json)
+            .contains(r#""verdict":"fail","expected":"11387""#));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("csv".parse::<OutputFormat>(), Ok(OutputFormat::Csv));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A value with a key, ordered so a [`BinaryHeap`] of them pops the lowest key first.
+pub struct MinHeapNode<T>(pub usize, pub T);
+
+impl<T> MinHeapNode<T> {
+    pub fn new(key: usize, value: T) -> Self {
+        Self(key, value)
+    }
+}
+
+impl<T> Eq for MinHeapNode<T> {}
+
+impl<T> PartialEq for MinHeapNode<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> PartialOrd for MinHeapNode<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for MinHeapNode<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Swap the order to be used in a min heap.
+        other.0.cmp(&self.0)
+    }
+}
+
+/// The cheapest path a search found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    /// The total cost of the steps, which is the number of steps for a breadth-first search.
+    pub cost: usize,
+    /// Every state from the start to the goal, including both.
+    pub states: Vec<S>,
+}
+
+/// Every shortest path from the start to the cheapest goals, kept as the states each state on
+/// them can be reached from.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    start: S,
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    fn new(start: S) -> Self {
+        Self {
+            start: start.clone(),
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        }
+    }
+
+    /// Records reaching `next` from `state` with a path costing `distance`, returning whether it
+    /// is the first path that cheap so `next` needs exploring.
+    fn reach(&mut self, state: &S, next: S, distance: usize, all: bool) -> bool {
+        match self.distances.get(&next) {
+            Some(best) if distance > *best => false,
+            Some(best) if distance == *best => {
+                if all {
+                    self.predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                }
+
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![state.clone()]);
+
+                true
+            }
+        }
+    }
+
+    /// The cost of the paths to the goals, or `None` when no goal could be reached.
+    pub fn cost(&self) -> Option<usize> {
+        self.goals.first().map(|goal| self.distances[goal])
+    }
+
+    /// The goals that the shortest paths lead to, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// One of the shortest paths, to the first goal found.
+    pub fn path(&self) -> Option<Path<S>> {
+        let goal = self.goals.first()?;
+        let mut states = vec![goal.clone()];
+
+        while let Some(previous) = self.predecessors.get(states.last()?) {
+            states.push(previous[0].clone());
+        }
+
+        states.reverse();
+
+        Some(Path {
+            cost: self.distances[goal],
+            states,
+        })
+    }
+
+    /// Every state on any of the shortest paths to any of the goals.
+    pub fn states(&self) -> HashSet<S> {
+        self.states_before(self.goals.clone())
+    }
+
+    /// The number of different shortest paths from the start to `goal`.
+    pub fn count_paths(&self, goal: &S) -> usize {
+        let states = self.states_before(vec![goal.clone()]);
+        let mut states = states.into_iter().collect::<Vec<_>>();
+
+        // Every step costs something, so a state's predecessors always come before it.
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<S, usize> = HashMap::from([(self.start.clone(), 1)]);
+
+        for state in states {
+            if let Some(previous) = self.predecessors.get(&state) {
+                let count = previous.iter().filter_map(|p| counts.get(p)).sum();
+                counts.insert(state, count);
+            }
+        }
+
+        counts.get(goal).copied().unwrap_or(0)
+    }
+
+    /// Every state on the shortest paths to `ends`, including them.
+    fn states_before(&self, ends: Vec<S>) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut stack = ends;
+
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                if let Some(previous) = self.predecessors.get(&state) {
+                    stack.extend(previous.iter().cloned());
+                }
+            }
+        }
+
+        states
+    }
+}
+
+/// The shortest path from `start` to a state passing `is_goal`, where every step costs 1.
+pub fn bfs<S, I, F, G>(start: S, successors: F, is_goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(start, successors, is_goal, false).path()
+}
+
+/// Every shortest path from `start` to the nearest states passing `is_goal`, where every step
+/// costs 1. With no reachable goal, it explores everything reachable from `start`.
+pub fn bfs_all<S, I, F, G>(start: S, successors: F, is_goal: G) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(start, successors, is_goal, true)
+}
+
+/// The cheapest path from `start` to a state passing `is_goal`, where `successors` gives the
+/// states one step away along with what the step costs.
+pub fn dijkstra<S, I, F, G>(start: S, successors: F, is_goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, successors, |_| 0, is_goal, false).path()
+}
+
+/// Every cheapest path from `start` to the cheapest states passing `is_goal`, where steps cost
+/// at least 1.
+pub fn dijkstra_all<S, I, F, G>(start: S, successors: F, is_goal: G) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, successors, |_| 0, is_goal, true)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which should never guess more than the real cost from a
+/// state to the nearest goal, like the Manhattan distance on a grid.
+pub fn astar<S, I, F, H, G>(start: S, successors: F, heuristic: H, is_goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, successors, heuristic, is_goal, false).path()
+}
+
+fn breadth_first<S, I, F, G>(
+    start: S,
+    mut successors: F,
+    mut is_goal: G,
+    all: bool,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = VecDeque::from([(0, start)]);
+
+    while let Some((distance, state)) = queue.pop_front() {
+        if paths.cost().is_some_and(|best| distance > best) {
+            break;
+        }
+
+        if is_goal(&state) {
+            paths.goals.push(state);
+
+            if !all {
+                break;
+            }
+
+            continue;
+        }
+
+        for next in successors(&state) {
+            if paths.reach(&state, next.clone(), distance + 1, all) {
+                queue.push_back((distance + 1, next));
+            }
+        }
+    }
+
+    paths
+}
+
+fn best_first<S, I, F, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+    all: bool,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = BinaryHeap::new();
+    let mut visited = HashSet::new();
+
+    queue.push(MinHeapNode::new(heuristic(&start), (0, start)));
+
+    while let Some(MinHeapNode(_, (distance, state))) = queue.pop() {
+        if paths.cost().is_some_and(|best| distance > best) {
+            break;
+        }
+
+        // A cheaper way here was found after this one was queued.
+        if distance > paths.distances[&state] || !visited.insert(state.clone()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            paths.goals.push(state);
+
+            if !all {
+                break;
+            }
+
+            continue;
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+
+            if paths.reach(&state, next.clone(), next_distance, all) {
+                let key = next_distance + heuristic(&next);
+                queue.push(MinHeapNode::new(key, (next_distance, next)));
+            }
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::neighbors::Neighbors;
+    use crate::point::Point;
+
+    const MAZE: &str = "S..#\n.#..\n...E\n";
+
+    fn open_neighbors(grid: &Grid<char>) -> impl FnMut(&Point) -> Vec<Point> + '_ {
+        |point| {
+            grid.neighbors_where(*point, Neighbors::four, |c| *c != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse(MAZE, Ok).unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let path = bfs(Point::new(0, 0), open_neighbors(&grid), |p| *p == end).unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(path.states[0], Point::new(0, 0));
+        assert_eq!(path.states[5], end);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| step[0].manhattan(&step[1]) == 1));
+
+        assert_eq!(
+            bfs(Point::new(0, 0), open_neighbors(&grid), |_| false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bfs_all() {
+        let grid = Grid::new(3, 3, '.');
+        let end = Point::new(2, 2);
+
+        let paths = bfs_all(Point::new(0, 0), open_neighbors(&grid), |p| *p == end);
+
+        assert_eq!(paths.cost(), Some(4));
+        assert_eq!(paths.goals(), [end]);
+        assert_eq!(paths.count_paths(&end), 6);
+        assert_eq!(paths.states().len(), 9);
+        assert_eq!(paths.count_paths(&Point::new(1, 1)), 2);
+
+        // Every goal as near as the nearest one counts, which is the three points 2 steps away.
+        let paths = bfs_all(Point::new(0, 0), open_neighbors(&grid), |p| {
+            p.row + p.col == 2
+        });
+        assert_eq!(paths.goals().len(), 3);
+        assert_eq!(paths.states().len(), 6);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Stepping back or along the top row is dear, so the cheapest paths step down first.
+        let grid = Grid::new(3, 3, '.');
+        let end = Point::new(2, 2);
+        let successors = |point: &Point| {
+            grid.neighbors(*point)
+                .map(|next| {
+                    let forwards = next.row > point.row || next.col > point.col;
+                    let top_row = point.row == 0 && next.row == 0;
+
+                    (next, if forwards && !top_row { 1 } else { 5 })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(Point::new(0, 0), successors, |p| *p == end).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states[1], Point::new(1, 0));
+
+        let path = astar(
+            Point::new(0, 0),
+            successors,
+            |p| p.manhattan(&end),
+            |p| *p == end,
+        );
+        assert_eq!(path.unwrap().cost, 4);
+
+        let paths = dijkstra_all(Point::new(0, 0), successors, |p| *p == end);
+        assert_eq!(paths.cost(), Some(4));
+        assert_eq!(paths.count_paths(&end), 3);
+        assert_eq!(paths.states().len(), 7);
+    }
+}
@@ -0,0 +1,608 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::alloc::{self, AllocStats};
+use crate::{
+    bench, instrument, BenchOptions, DayReport, PartReport, PuzzleError, PuzzleResult, RunOptions,
+    Status, Timing,
+};
+
+/// A day's puzzle: how to parse the input, and how to solve both parts from the parsed input.
+pub trait Solution {
+    const YEAR: usize;
+    const DAY: usize;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(contents: &str) -> PuzzleResult<Self::Input>;
+
+    fn part_1(input: &Self::Input) -> PuzzleResult<Self::Part1>;
+
+    fn part_2(input: &Self::Input) -> PuzzleResult<Self::Part2>;
+}
+
+/// Parses and parts that timed out but haven't stopped yet.
+static STILL_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Set on a thread running a step with a timeout, and raised once that step times out.
+    static CANCEL: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Whether the step running on this thread has timed out, so its answer is no longer wanted.
+/// Threads can't be stopped from the outside, so a long-running solution can check this to give
+/// up early instead of slowing down everything that runs after it. Benchmarks check it between
+/// runs.
+pub fn cancelled() -> bool {
+    CANCEL.with_borrow(|cancel| {
+        cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::SeqCst))
+    })
+}
+
+/// How many timed-out parses and parts are still running. While any are, they compete with
+/// whatever runs next, so its timings are unreliable.
+pub fn still_running() -> usize {
+    STILL_RUNNING.load(Ordering::SeqCst)
+}
+
+/// Parsed input behind a type-erased, shareable pointer.
+pub type ParsedInput = Arc<dyn Any + Send + Sync>;
+
+/// A [`Solution`] with its types erased, so days can be stored and run together.
+pub trait DynSolution: Send + Sync {
+    fn year(&self) -> usize;
+
+    fn day(&self) -> usize;
+
+    fn parse(&self, contents: &str) -> PuzzleResult<ParsedInput>;
+
+    /// Solves `part` from input returned by [`DynSolution::parse`], formatting the answer.
+    fn solve(&self, part: usize, input: &ParsedInput) -> PuzzleResult<String>;
+
+    /// Parses the input once and runs the selected parts on it. Errors replace the answer of the
+    /// part they happened in, so the other part still runs, and the first one is kept in the
+    /// report. A panic, or a step running past the timeout, is reported as an error of its own.
+    fn run(&'static self, options: &RunOptions) -> DayReport
+    where
+        Self: 'static,
+    {
+        let (mut report, parsed) = start_day(self, options);
+
+        if let Some(parsed) = parsed {
+            let parts = selected_parts(options)
+                .into_iter()
+                .map(|part| run_part(self, options, part, &parsed))
+                .collect();
+
+            finish_day(&mut report, parts);
+        }
+
+        report
+    }
+}
+
+/// The parts `options` asks for.
+pub(crate) fn selected_parts(options: &RunOptions) -> Vec<usize> {
+    [1, 2]
+        .into_iter()
+        .filter(|part| options.includes_part(*part))
+        .collect()
+}
+
+/// Reads and parses a day's input, giving up on parsing after the timeout like a part. When that
+/// fails the report is already complete, with the error in place of every selected part, and
+/// there's no parsed input to run the parts on.
+pub(crate) fn start_day<S>(
+    solution: &'static S,
+    options: &RunOptions,
+) -> (DayReport, Option<ParsedInput>)
+where
+    S: DynSolution + ?Sized + 'static,
+{
+    let (year, day) = (solution.year(), solution.day());
+    let file = options.input.describe(year, day);
+
+    let mut report = DayReport {
+        year,
+        day,
+        input: file.clone(),
+        parse: None,
+        parts: vec![],
+        error: None,
+    };
+
+    let bench = options.bench;
+    let parsed = options
+        .input
+        .read(year, day)
+        .map_err(PuzzleError::io)
+        .and_then(|contents| {
+            with_timeout(options.timeout, move || {
+                timed(bench, || solution.parse(&contents))
+            })
+        });
+
+    match parsed {
+        Ok((parsed, timing)) => {
+            report.parse = Some(timing);
+
+            (report, Some(parsed))
+        }
+        Err(error) => {
+            let error = error.with_context(day, &file);
+
+            report.parts = selected_parts(options)
+                .into_iter()
+                .map(|part| PartReport {
+                    part,
+                    status: Status::of(&error),
+                    answer: Err(error.to_string()),
+                    timing: None,
+                    memory: None,
+                    verdict: None,
+                })
+                .collect();
+            report.error = Some(error);
+
+            (report, None)
+        }
+    }
+}
+
+/// Solves one part from the parsed input, returning its error as well when it fails.
+pub(crate) fn run_part<S>(
+    solution: &'static S,
+    options: &RunOptions,
+    part: usize,
+    parsed: &ParsedInput,
+) -> (PartReport, Option<PuzzleError>)
+where
+    S: DynSolution + ?Sized + 'static,
+{
+    let bench = options.bench;
+    let parsed = parsed.clone();
+    let result = with_timeout(options.timeout, move || {
+        timed_with_memory(bench, || solution.solve(part, &parsed))
+    });
+
+    match result {
+        Ok((answer, timing, memory)) => (
+            PartReport {
+                part,
+                status: Status::Ok,
+                answer: Ok(answer),
+                timing: Some(timing),
+                memory,
+                verdict: None,
+            },
+            None,
+        ),
+        Err(error) => {
+            let (year, day) = (solution.year(), solution.day());
+            let error = error.with_context(day, &options.input.describe(year, day));
+
+            (
+                PartReport {
+                    part,
+                    status: Status::of(&error),
+                    answer: Err(error.to_string()),
+                    timing: None,
+                    memory: None,
+                    verdict: None,
+                },
+                Some(error),
+            )
+        }
+    }
+}
+
+/// Adds the parts to the report in part order, keeping the error of the first part that failed.
+pub(crate) fn finish_day(
+    report: &mut DayReport,
+    mut parts: Vec<(PartReport, Option<PuzzleError>)>,
+) {
+    parts.sort_by_key(|(part, _)| part.part);
+
+    for (part, error) in parts {
+        if let Some(error) = error {
+            report.error.get_or_insert(error);
+        }
+
+        report.parts.push(part);
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn year(&self) -> usize {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn parse(&self, contents: &str) -> PuzzleResult<ParsedInput> {
+        S::parse(contents).map(|input| Arc::new(input) as ParsedInput)
+    }
+
+    fn solve(&self, part: usize, input: &ParsedInput) -> PuzzleResult<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or_else(|| PuzzleError::solve(format!("input wasn't parsed by day {}", S::DAY)))?;
+
+        match part {
+            1 => S::part_1(input).map(|answer| answer.to_string()),
+            2 => S::part_2(input).map(|answer| answer.to_string()),
+            _ => Err(PuzzleError::solve(format!("there's no part {part}"))),
+        }
+    }
+}
+
+/// Runs `f`, catching a panic. With a timeout `f` runs on a thread of its own, which is left
+/// behind if it doesn't finish in time: it's told it was [`cancelled`], and counted as
+/// [`still_running`] until it stops.
+fn with_timeout<T, F>(timeout: Option<Duration>, f: F) -> PuzzleResult<T>
+where
+    F: FnOnce() -> PuzzleResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return catch_panic(f);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let thread_cancel = cancel.clone();
+
+    thread::spawn(move || {
+        CANCEL.set(Some(thread_cancel.clone()));
+
+        let result = catch_panic(f);
+
+        // Whichever of this and the timeout raises the flag first decides whether this step
+        // was counted as still running.
+        if thread_cancel.swap(true, Ordering::SeqCst) {
+            STILL_RUNNING.fetch_sub(1, Ordering::SeqCst);
+        }
+
+        // The receiver is gone when the step timed out.
+        let _ = sender.send(result);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            STILL_RUNNING.fetch_add(1, Ordering::SeqCst);
+
+            if cancel.swap(true, Ordering::SeqCst) {
+                // It finished just after the timeout, so it isn't running anymore.
+                STILL_RUNNING.fetch_sub(1, Ordering::SeqCst);
+            }
+
+            Err(PuzzleError::timeout(timeout))
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(PuzzleError::panic("the step stopped without an answer"))
+        }
+    }
+}
+
+/// Runs `f` once, or benchmarks it when that was asked for.
+fn timed<T, F>(bench: Option<BenchOptions>, mut f: F) -> PuzzleResult<(T, Timing)>
+where
+    F: FnMut() -> PuzzleResult<T>,
+{
+    match &bench {
+        Some(bench_options) => {
+            let f = || {
+                if cancelled() {
+                    return Err(PuzzleError::solve("stopped benchmarking after the timeout"));
+                }
+
+                f()
+            };
+
+            bench::bench(bench_options, f).map(|(value, stats)| (value, Timing::Bench(stats)))
+        }
+        None => {
+            let (result, elapsed) = instrument(f);
+            result.map(|value| (value, Timing::Single(elapsed)))
+        }
+    }
+}
+
+/// Like [`timed`], also counting the allocations of each run when the counting allocator is
+/// installed. Only the runs themselves are counted, not the benchmark's bookkeeping.
+fn timed_with_memory<T, F>(
+    bench: Option<BenchOptions>,
+    mut f: F,
+) -> PuzzleResult<(T, Timing, Option<AllocStats>)>
+where
+    F: FnMut() -> PuzzleResult<T>,
+{
+    let mut memory: Option<AllocStats> = None;
+    let mut runs = 0;
+
+    let result = timed(bench, || {
+        let (result, stats) = alloc::measure(&mut f);
+
+        if let Some(stats) = stats {
+            memory.get_or_insert_default().add(stats);
+        }
+
+        runs += 1;
+        result
+    });
+
+    result.map(|(value, timing)| (value, timing, memory.map(|memory| memory.per_run(runs))))
+}
+
+/// Runs `f`, turning a panic into an error with the panic's message.
+fn catch_panic<T, F>(f: F) -> PuzzleResult<T>
+where
+    F: FnOnce() -> PuzzleResult<T>,
+{
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map_or("unknown panic", |message| message)
+                .to_string(),
+        };
+
+        Err(PuzzleError::panic(message))
+    })
+}
+
+/// Every available solution, in year and day order.
+pub struct Registry {
+    solutions: &'static [&'static dyn DynSolution],
+}
+
+impl Registry {
+    /// `solutions` should be sorted by year and day, with at most one solution per day.
+    pub const fn new(solutions: &'static [&'static dyn DynSolution]) -> Self {
+        Self { solutions }
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<&'static dyn DynSolution> {
+        self.solutions
+            .iter()
+            .find(|solution| solution.year() == year && solution.day() == day)
+            .copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn DynSolution> + '_ {
+        self.solutions.iter().copied()
+    }
+
+    /// The solutions of `year`, in day order.
+    pub fn year(&self, year: usize) -> impl Iterator<Item = &'static dyn DynSolution> + '_ {
+        self.iter().filter(move |solution| solution.year() == year)
+    }
+
+    pub fn days(&self, year: usize) -> Vec<usize> {
+        self.year(year).map(|solution| solution.day()).collect()
+    }
+
+    /// The years with at least one solution.
+    pub fn years(&self) -> Vec<usize> {
+        let mut years = self
+            .iter()
+            .map(|solution| solution.year())
+            .collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputSource;
+    use std::time::Duration;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const YEAR: usize = 2024;
+        const DAY: usize = 99;
+
+        type Input = Vec<usize>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(contents: &str) -> PuzzleResult<Vec<usize>> {
+            crate::lines(contents)
+                .map(|line| line.parse(line.text))
+                .collect()
+        }
+
+        fn part_1(input: &Vec<usize>) -> PuzzleResult<usize> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_2(input: &Vec<usize>) -> PuzzleResult<String> {
+            input
+                .iter()
+                .max()
+                .map(|max| format!("max {max}"))
+                .ok_or(PuzzleError::solve("no numbers"))
+        }
+    }
+
+    /// Never finishes parsing "hang" or part 1 until cancelled, and panics in part 2.
+    struct Stuck;
+
+    fn wait_until_cancelled() {
+        while !cancelled() {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    impl Solution for Stuck {
+        const YEAR: usize = 2024;
+        const DAY: usize = 98;
+
+        type Input = ();
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(contents: &str) -> PuzzleResult<()> {
+            if contents == "hang" {
+                wait_until_cancelled();
+            }
+
+            Ok(())
+        }
+
+        fn part_1(_: &()) -> PuzzleResult<usize> {
+            wait_until_cancelled();
+
+            Ok(0)
+        }
+
+        fn part_2(_: &()) -> PuzzleResult<usize> {
+            let distances: Vec<usize> = vec![];
+            Ok(distances[0])
+        }
+    }
+
+    const REGISTRY: Registry = Registry::new(&[&Stuck, &Sums]);
+
+    fn run(contents: &str, part: Option<usize>) -> DayReport {
+        let options = RunOptions {
+            part,
+            input: InputSource::Inline(contents.to_string()),
+            ..RunOptions::default()
+        };
+
+        REGISTRY.get(2024, 99).unwrap().run(&options)
+    }
+
+    #[test]
+    fn test_registry() {
+        assert_eq!(REGISTRY.years(), [2024]);
+        assert_eq!(REGISTRY.days(2024), [98, 99]);
+        assert!(REGISTRY.days(2023).is_empty());
+        assert!(REGISTRY.get(2024, 1).is_none());
+        assert!(REGISTRY.get(2023, 99).is_none());
+    }
+
+    #[test]
+    fn test_run_solution() {
+        let report = run("1\n2\n3\n", None);
+        let answers = report
+            .parts
+            .iter()
+            .map(|part| part.answer.clone())
+            .collect::<Vec<_>>();
+
+        assert!(report.is_ok());
+        assert_eq!(answers, [Ok("6".to_string()), Ok("max 3".to_string())]);
+    }
+
+    #[test]
+    fn test_run_errors() {
+        let report = run("", Some(2));
+
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(
+            report.error.unwrap().to_string(),
+            "day 99: <inline>: no numbers"
+        );
+
+        let report = run("1\nx\n", None);
+
+        assert!(report.parse.is_none());
+        assert_eq!(report.parts.len(), 2);
+        assert!(report.parts[0].answer.as_ref().unwrap_err().contains("2:1"));
+    }
+
+    fn run_stuck(contents: &str) -> DayReport {
+        let options = RunOptions {
+            input: InputSource::Inline(contents.to_string()),
+            timeout: Some(Duration::from_millis(50)),
+            ..RunOptions::default()
+        };
+
+        REGISTRY.get(2024, 98).unwrap().run(&options)
+    }
+
+    fn wait_until_stopped() {
+        for _ in 0..100 {
+            if still_running() == 0 {
+                return;
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        panic!("{} timed-out steps never stopped", still_running());
+    }
+
+    #[test]
+    fn test_timeout_and_panic() {
+        let report = run_stuck("");
+        let answers = report
+            .parts
+            .iter()
+            .map(|part| part.answer.clone().unwrap_err())
+            .collect::<Vec<_>>();
+
+        assert_eq!(answers[0], "day 98: <inline>: TIMEOUT after 50ms");
+        assert!(answers[1].starts_with("day 98: <inline>: PANIC: index out of bounds"));
+        assert!(report.parts.iter().all(|part| part.timing.is_none()));
+        assert_eq!(
+            report
+                .parts
+                .iter()
+                .map(|part| part.status)
+                .collect::<Vec<_>>(),
+            [Status::Timeout, Status::Panic]
+        );
+
+        wait_until_stopped();
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        let report = run_stuck("hang");
+
+        assert!(report.parse.is_none());
+        assert!(report
+            .parts
+            .iter()
+            .all(|part| part.status == Status::Timeout));
+        assert_eq!(
+            report.error.unwrap().to_string(),
+            "day 98: <inline>: TIMEOUT after 50ms"
+        );
+
+        wait_until_stopped();
+    }
+
+    #[test]
+    fn test_wrong_input_type() {
+        let input: ParsedInput = Arc::new("not a list");
+
+        assert!(Sums.solve(1, &input).is_err());
+        assert!(Sums.solve(3, &Sums.parse("1").unwrap()).is_err());
+    }
+}
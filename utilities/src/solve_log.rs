@@ -0,0 +1,531 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::json::{self, Value};
+use crate::DayReport;
+
+/// Where the solve log is kept by default, relative to the repository root.
+pub const DEFAULT_LOG_FILE: &str = "solve_log.jsonl";
+
+const RENDER_WIDTH: usize = 110;
+
+/// A point in time to the minute. Times typed into the old notes are local time, while the ones
+/// the runner records are UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+    /// Hour and minute, when the time of day is known.
+    pub time: Option<(u32, u32)>,
+}
+
+impl Timestamp {
+    pub fn now() -> Self {
+        let elapsed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self::from_unix_secs(elapsed.as_secs())
+    }
+
+    fn from_unix_secs(secs: u64) -> Self {
+        let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+        let minutes = (secs % 86_400) / 60;
+
+        Self {
+            year,
+            month,
+            day,
+            time: Some(((minutes / 60) as u32, (minutes % 60) as u32)),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=31).contains(&self.day)
+            && self
+                .time
+                .is_none_or(|(hour, minute)| hour < 24 && minute < 60)
+    }
+
+    /// The layout used in the old notes, like `2024-12-1 7:16pm`.
+    pub fn to_notes_string(&self) -> String {
+        let date = format!("{}-{}-{}", self.year, self.month, self.day);
+
+        match self.time {
+            Some((hour, minute)) => {
+                let suffix = if hour < 12 { "am" } else { "pm" };
+                let hour = match hour % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+
+                format!("{date} {hour}:{minute:02}{suffix}")
+            }
+            None => date,
+        }
+    }
+}
+
+/// Converts days since the Unix epoch to a (year, month, day) date in the proleptic Gregorian
+/// calendar, using Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (u32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as u32, month as u32, day as u32)
+}
+
+/// Written as `2024-12-01T19:16`, or just the date when the time isn't known.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)?;
+
+        if let Some((hour, minute)) = self.time {
+            write!(f, "T{hour:02}:{minute:02}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid timestamp '{s}'");
+        let number = |value: &str| value.parse::<u32>().map_err(|_| invalid());
+
+        let (date, time) = match s.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (s, None),
+        };
+
+        let [year, month, day] = date.split('-').collect::<Vec<_>>()[..] else {
+            return Err(invalid());
+        };
+
+        let time = match time.map(|time| time.split_once(':')) {
+            Some(Some((hour, minute))) => Some((number(hour)?, number(minute)?)),
+            Some(None) => return Err(invalid()),
+            None => None,
+        };
+
+        let timestamp = Timestamp {
+            year: number(year)?,
+            month: number(month)?,
+            day: number(day)?,
+            time,
+        };
+
+        if timestamp.is_valid() {
+            Ok(timestamp)
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+/// One line of the solve log.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LogEntry {
+    pub year: usize,
+    pub day: usize,
+    /// The part that was solved, or `None` for an entry about the day itself, like when it was
+    /// started.
+    pub part: Option<usize>,
+    pub time: Option<Timestamp>,
+    pub answer: Option<String>,
+    pub runtime: Option<Duration>,
+    /// Global leaderboard rank.
+    pub rank: Option<usize>,
+    pub note: Option<String>,
+}
+
+impl LogEntry {
+    pub fn to_json(&self) -> Value {
+        Value::object([
+            ("year", Value::from(self.year)),
+            ("day", Value::from(self.day)),
+            ("part", Value::from(self.part)),
+            ("time", Value::from(self.time.map(|time| time.to_string()))),
+            ("answer", Value::from(self.answer.clone())),
+            (
+                "runtime_ns",
+                Value::from(self.runtime.map(|runtime| runtime.as_nanos() as usize)),
+            ),
+            ("rank", Value::from(self.rank)),
+            ("note", Value::from(self.note.clone())),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let field = |name: &str| value.get(name).filter(|value| !value.is_null());
+        let number = |name: &str| {
+            field(name)
+                .map(|value| {
+                    value
+                        .as_usize()
+                        .ok_or_else(|| format!("'{name}' should be a whole number"))
+                })
+                .transpose()
+        };
+        let string = |name: &str| {
+            field(name)
+                .map(|value| {
+                    value
+                        .as_str()
+                        .map(str::to_string)
+                        .ok_or_else(|| format!("'{name}' should be a string"))
+                })
+                .transpose()
+        };
+
+        Ok(LogEntry {
+            year: number("year")?.ok_or("missing 'year'")?,
+            day: number("day")?.ok_or("missing 'day'")?,
+            part: number("part")?,
+            time: string("time")?.map(|time| time.parse()).transpose()?,
+            answer: string("answer")?,
+            runtime: number("runtime_ns")?.map(|ns| Duration::from_nanos(ns as u64)),
+            rank: number("rank")?,
+            note: string("note")?,
+        })
+    }
+}
+
+/// An entry for each part in the report that was solved.
+pub fn solved_entries(report: &DayReport, time: Timestamp) -> Vec<LogEntry> {
+    report
+        .parts
+        .iter()
+        .filter_map(|part| {
+            Some(LogEntry {
+                year: report.year,
+                day: report.day,
+                part: Some(part.part),
+                time: Some(time),
+                answer: Some(part.answer.clone().ok()?),
+                runtime: part.timing.map(|timing| timing.duration()),
+                ..LogEntry::default()
+            })
+        })
+        .collect()
+}
+
+/// Reads every entry in a solve log. A missing file is an empty log.
+pub fn read_log(path: &Path) -> io::Result<Vec<LogEntry>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error),
+    };
+
+    parse_log(&contents).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}:{error}", path.display()),
+        )
+    })
+}
+
+/// Parses a solve log, which has one JSON object per line.
+pub fn parse_log(contents: &str) -> Result<Vec<LogEntry>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            json::parse(line)
+                .and_then(|value| LogEntry::from_json(&value))
+                .map_err(|error| format!("{}: {error}", idx + 1))
+        })
+        .collect()
+}
+
+/// Appends entries to a solve log, creating it if needed.
+pub fn append_log(path: &Path, entries: &[LogEntry]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_json())?;
+    }
+
+    Ok(())
+}
+
+/// The latest recorded answer for each year, day and part, as `(year, day, part, answer)` in
+/// that order.
+pub fn latest_answers(entries: &[LogEntry]) -> Vec<(usize, usize, usize, &str)> {
+    let mut answers: Vec<(usize, usize, usize, &str)> = vec![];
+
+    for entry in entries {
+        let (Some(part), Some(answer)) = (entry.part, &entry.answer) else {
+            continue;
+        };
+
+        match answers.iter_mut().find(|(year, day, existing, _)| {
+            *year == entry.year && *day == entry.day && *existing == part
+        }) {
+            Some(existing) => existing.3 = answer,
+            None => answers.push((entry.year, entry.day, part, answer)),
+        }
+    }
+
+    answers.sort_by_key(|(year, day, part, _)| (*year, *day, *part));
+    answers
+}
+
+/// Renders the log in the layout of the old hand-written notes, one block per day. When the log
+/// covers more than one year, each year's days follow a heading with the year.
+pub fn render(entries: &[LogEntry]) -> String {
+    let mut days = entries
+        .iter()
+        .map(|entry| (entry.year, entry.day))
+        .collect::<Vec<_>>();
+    days.sort();
+    days.dedup();
+
+    let several_years = days.first().map(|(year, _)| year) != days.last().map(|(year, _)| year);
+    let mut last_year = None;
+
+    let blocks = days
+        .into_iter()
+        .map(|(year, day)| {
+            let mut block = String::new();
+
+            if several_years && last_year != Some(year) {
+                block.push_str(&format!("{year}\n\n"));
+                last_year = Some(year);
+            }
+
+            block.push_str(&format!("Day {day}:\n"));
+
+            for entry in entries
+                .iter()
+                .filter(|entry| entry.year == year && entry.day == day)
+            {
+                block.push_str(&wrap(&format!("    - {}", render_entry(entry))));
+            }
+
+            block
+        })
+        .collect::<Vec<_>>();
+
+    blocks.join("\n")
+}
+
+/// Wraps a line to `RENDER_WIDTH` columns, indenting continuation lines under the item's text.
+fn wrap(line: &str) -> String {
+    let mut wrapped = String::new();
+    let mut current = String::new();
+
+    for word in line.split(' ') {
+        if current.chars().count() + word.chars().count() >= RENDER_WIDTH
+            && !current.trim().is_empty()
+        {
+            wrapped.push_str(current.trim_end());
+            wrapped.push('\n');
+            current = "      ".to_string();
+        }
+
+        current.push_str(word);
+        current.push(' ');
+    }
+
+    wrapped.push_str(current.trim_end());
+    wrapped.push('\n');
+    wrapped
+}
+
+fn render_entry(entry: &LogEntry) -> String {
+    let time = entry.time.map(|time| time.to_notes_string());
+
+    let mut line = match (entry.part, time) {
+        (Some(part), Some(time)) => format!("part{part}: {time}"),
+        (Some(part), None) => format!("part{part}:"),
+        (None, Some(time)) => format!("Started {time}"),
+        (None, None) => String::new(),
+    };
+
+    if entry.answer.is_some() || entry.runtime.is_some() {
+        let fields = [
+            entry.answer.clone(),
+            entry.runtime.map(|runtime| format!("{runtime:?}")),
+        ];
+
+        line.push_str(&format!(
+            " [{}]",
+            fields.into_iter().flatten().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    if let Some(rank) = entry.rank {
+        line.push_str(&format!(" ({rank})"));
+    }
+
+    match (&entry.note, line.is_empty()) {
+        (Some(note), true) => note.clone(),
+        (Some(note), false) => format!("{line}, {note}"),
+        (None, _) => line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(day: usize, part: usize, answer: &str) -> LogEntry {
+        LogEntry {
+            year: 2024,
+            day,
+            part: Some(part),
+            time: "2024-12-10T20:35".parse().ok(),
+            answer: Some(answer.to_string()),
+            runtime: Some(Duration::from_micros(2699)),
+            rank: Some(68760),
+            ..LogEntry::default()
+        }
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let timestamp = "2024-12-01T19:16".parse::<Timestamp>().unwrap();
+
+        assert_eq!(timestamp.to_string(), "2024-12-01T19:16");
+        assert_eq!(timestamp.to_notes_string(), "2024-12-1 7:16pm");
+        assert_eq!("2025-01-05".parse::<Timestamp>().unwrap().time, None);
+        assert!("2024-13-01".parse::<Timestamp>().is_err());
+        assert!("yesterday".parse::<Timestamp>().is_err());
+
+        assert_eq!(
+            Timestamp::from_unix_secs(1_733_080_560).to_string(),
+            "2024-12-01T19:16"
+        );
+    }
+
+    #[test]
+    fn test_log_round_trip() {
+        let entries = vec![
+            LogEntry {
+                year: 2024,
+                day: 7,
+                time: "2024-12-10T19:56".parse().ok(),
+                ..LogEntry::default()
+            },
+            solved(7, 1, "3749"),
+            LogEntry {
+                note: Some("rewrote the \"fast\" version".to_string()),
+                ..solved(7, 2, "11387")
+            },
+        ];
+
+        let contents = entries
+            .iter()
+            .map(|entry| format!("{}\n", entry.to_json()))
+            .collect::<String>();
+
+        assert_eq!(parse_log(&contents).unwrap(), entries);
+        assert!(parse_log("{\"part\": 1}").is_err());
+        assert!(parse_log("{\"day\": 7, \"part\": 1}").is_err());
+    }
+
+    #[test]
+    fn test_latest_answers() {
+        let entries = [
+            solved(2, 1, "1"),
+            solved(1, 2, "2"),
+            LogEntry {
+                year: 2023,
+                ..solved(2, 1, "4")
+            },
+            solved(2, 1, "3"),
+        ];
+
+        assert_eq!(
+            latest_answers(&entries),
+            [(2023, 2, 1, "4"), (2024, 1, 2, "2"), (2024, 2, 1, "3")]
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        let line = format!("    - part1: [1] (2), {}", "word ".repeat(30));
+        let wrapped = wrap(&line);
+        let lines = wrapped.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| line.len() < RENDER_WIDTH));
+        assert!(lines[1].starts_with("      word"));
+    }
+
+    #[test]
+    fn test_render() {
+        let entries = [
+            LogEntry {
+                year: 2024,
+                day: 7,
+                time: "2024-12-10T19:56".parse().ok(),
+                ..LogEntry::default()
+            },
+            LogEntry {
+                note: Some("shared the algorithm with part 1".to_string()),
+                ..solved(7, 1, "3749")
+            },
+            LogEntry {
+                year: 2024,
+                day: 7,
+                note: Some("Made improvements after reading on reddit".to_string()),
+                ..LogEntry::default()
+            },
+        ];
+
+        assert_eq!(
+            render(&entries),
+            "Day 7:\n\
+             \x20   - Started 2024-12-10 7:56pm\n\
+             \x20   - part1: 2024-12-10 8:35pm [3749, 2.699ms] (68760), shared the algorithm with part 1\n\
+             \x20   - Made improvements after reading on reddit\n"
+        );
+    }
+
+    #[test]
+    fn test_render_years() {
+        let entries = [
+            solved(7, 1, "3749"),
+            LogEntry {
+                year: 2023,
+                rank: None,
+                ..solved(1, 1, "142")
+            },
+        ];
+
+        assert_eq!(
+            render(&entries),
+            "2023\n\n\
+             Day 1:\n\
+             \x20   - part1: 2024-12-10 8:35pm [142, 2.699ms]\n\
+             \n\
+             2024\n\n\
+             Day 7:\n\
+             \x20   - part1: 2024-12-10 8:35pm [3749, 2.699ms] (68760)\n"
+        );
+    }
+}
@@ -0,0 +1,615 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::json::{self, Value};
+
+/// Where submissions are recorded by default, relative to the repository root.
+pub const DEFAULT_HISTORY_FILE: &str = "submissions.jsonl";
+
+/// How long the site makes you wait after a wrong answer when it doesn't say.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Which way a wrong answer was off, when the site says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// How long to wait before the next answer is accepted.
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, so this one wasn't checked.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part was already solved, so this one wasn't checked.
+    AlreadySolved,
+    /// A response that couldn't be made sense of, with its text.
+    Unknown(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it's too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it's too low")?,
+                    None => {}
+                }
+
+                match wait {
+                    Some(wait) => write!(f, ". Wait {} before trying again.", format_wait(*wait)),
+                    None => write!(f, "."),
+                }
+            }
+            Outcome::TooSoon { wait } => write!(
+                f,
+                "An answer was given too recently, {} left to wait",
+                format_wait(*wait)
+            ),
+            Outcome::AlreadySolved => write!(f, "This part is already solved"),
+            Outcome::Unknown(text) => write!(f, "Unexpected response: {text}"),
+        }
+    }
+}
+
+/// One answer that was sent to the site, and what came back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub outcome: Outcome,
+}
+
+impl Submission {
+    pub fn to_json(&self) -> Value {
+        let (outcome, hint, wait, message) = match &self.outcome {
+            Outcome::Correct => ("correct", None, None, None),
+            Outcome::Wrong { hint, wait } => ("wrong", *hint, *wait, None),
+            Outcome::TooSoon { wait } => ("too_soon", None, Some(*wait), None),
+            Outcome::AlreadySolved => ("already_solved", None, None, None),
+            Outcome::Unknown(text) => ("unknown", None, None, Some(text.clone())),
+        };
+
+        Value::object([
+            ("year", Value::from(self.year)),
+            ("day", Value::from(self.day)),
+            ("part", Value::from(self.part)),
+            ("answer", Value::from(self.answer.as_str())),
+            ("time", Value::from(self.time as usize)),
+            ("outcome", Value::from(outcome)),
+            (
+                "hint",
+                Value::from(hint.map(|hint| match hint {
+                    Hint::TooHigh => "too_high",
+                    Hint::TooLow => "too_low",
+                })),
+            ),
+            (
+                "wait_s",
+                Value::from(wait.map(|wait| wait.as_secs() as usize)),
+            ),
+            ("message", Value::from(message)),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let field = |name: &str| value.get(name).filter(|value| !value.is_null());
+        let number = |name: &str| {
+            field(name)
+                .map(|value| {
+                    value
+                        .as_usize()
+                        .ok_or_else(|| format!("'{name}' should be a whole number"))
+                })
+                .transpose()
+        };
+        let string = |name: &str| {
+            field(name)
+                .map(|value| {
+                    value
+                        .as_str()
+                        .ok_or_else(|| format!("'{name}' should be a string"))
+                })
+                .transpose()
+        };
+
+        let wait = number("wait_s")?.map(|secs| Duration::from_secs(secs as u64));
+        let hint = match string("hint")? {
+            None => None,
+            Some("too_high") => Some(Hint::TooHigh),
+            Some("too_low") => Some(Hint::TooLow),
+            Some(hint) => return Err(format!("unknown hint '{hint}'")),
+        };
+
+        let outcome = match string("outcome")?.ok_or("missing 'outcome'")? {
+            "correct" => Outcome::Correct,
+            "wrong" => Outcome::Wrong { hint, wait },
+            "too_soon" => Outcome::TooSoon {
+                wait: wait.ok_or("missing 'wait_s'")?,
+            },
+            "already_solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown(string("message")?.unwrap_or_default().to_string()),
+            outcome => return Err(format!("unknown outcome '{outcome}'")),
+        };
+
+        Ok(Submission {
+            year: number("year")?.ok_or("missing 'year'")?,
+            day: number("day")?.ok_or("missing 'day'")?,
+            part: number("part")?.ok_or("missing 'part'")?,
+            answer: string("answer")?.ok_or("missing 'answer'")?.to_string(),
+            time: number("time")?.ok_or("missing 'time'")? as u64,
+            outcome,
+        })
+    }
+
+    /// When the site accepts answers again after this submission, in seconds since the epoch.
+    fn cooldown_until(&self) -> u64 {
+        let wait = match &self.outcome {
+            Outcome::Wrong { wait, .. } => wait.unwrap_or(DEFAULT_COOLDOWN),
+            Outcome::TooSoon { wait } => *wait,
+            _ => Duration::ZERO,
+        };
+
+        self.time + wait.as_secs()
+    }
+}
+
+/// Why an answer wasn't submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong,
+    /// The answer is at least as high as one the site said is too high.
+    TooHigh {
+        than: String,
+    },
+    /// The answer is at most as low as one the site said is too low.
+    TooLow {
+        than: String,
+    },
+    Cooldown {
+        wait: Duration,
+    },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with {answer}")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and was wrong"),
+            Refusal::TooHigh { than } => write!(f, "{than} was already too high"),
+            Refusal::TooLow { than } => write!(f, "{than} was already too low"),
+            Refusal::Cooldown { wait } => {
+                write!(
+                    f,
+                    "the site needs {} more before the next answer",
+                    format_wait(*wait)
+                )
+            }
+        }
+    }
+}
+
+/// Checks an answer against what the history already says about it, and whether the site would
+/// accept an answer at `now` (seconds since the epoch).
+pub fn check_submission(
+    history: &[Submission],
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+    now: u64,
+) -> Result<(), Refusal> {
+    let previous = history
+        .iter()
+        .filter(|submission| {
+            submission.year == year && submission.day == day && submission.part == part
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(correct) = previous
+        .iter()
+        .find(|submission| submission.outcome == Outcome::Correct)
+    {
+        return Err(Refusal::AlreadySolved {
+            answer: correct.answer.clone(),
+        });
+    }
+
+    let number = answer.parse::<i128>().ok();
+
+    for submission in &previous {
+        let Outcome::Wrong { hint, .. } = submission.outcome else {
+            continue;
+        };
+
+        if submission.answer == answer {
+            return Err(Refusal::KnownWrong);
+        }
+
+        let (Some(number), Ok(wrong)) = (number, submission.answer.parse::<i128>()) else {
+            continue;
+        };
+
+        match hint {
+            Some(Hint::TooHigh) if number >= wrong => {
+                return Err(Refusal::TooHigh {
+                    than: submission.answer.clone(),
+                })
+            }
+            Some(Hint::TooLow) if number <= wrong => {
+                return Err(Refusal::TooLow {
+                    than: submission.answer.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    // The wait applies to every puzzle, not just the one that was answered.
+    let cooldown_until = history
+        .iter()
+        .map(Submission::cooldown_until)
+        .max()
+        .unwrap_or(0);
+
+    if cooldown_until > now {
+        return Err(Refusal::Cooldown {
+            wait: Duration::from_secs(cooldown_until - now),
+        });
+    }
+
+    Ok(())
+}
+
+/// Reads what the site answered, from the page it returns after a submission.
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+    let lower = text.to_lowercase();
+
+    if lower.contains("that's the right answer") {
+        Outcome::Correct
+    } else if lower.contains("that's not the right answer") {
+        let hint = if lower.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if lower.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        Outcome::Wrong {
+            hint,
+            wait: parse_wait_before(&lower, "before trying again"),
+        }
+    } else if lower.contains("you gave an answer too recently") {
+        Outcome::TooSoon {
+            wait: parse_wait_before(&lower, "left to wait").unwrap_or(DEFAULT_COOLDOWN),
+        }
+    } else if lower.contains("don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// The text of the page's `<article>`, or of the whole page if it has none, without tags and
+/// with whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .map(|start| {
+            let end = html[start..]
+                .find("</article>")
+                .map_or(html.len(), |end| start + end);
+
+            &html[start..end]
+        })
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the wait in phrases like "wait one minute before trying again", "wait 5 minutes before
+/// trying again" or "you have 1m 23s left to wait".
+fn parse_wait_before(text: &str, phrase: &str) -> Option<Duration> {
+    let end = text.find(phrase)?;
+    let words = text[..end]
+        .split_whitespace()
+        .rev()
+        .take_while(|word| *word != "wait" && *word != "have")
+        .collect::<Vec<_>>();
+
+    let mut seconds = 0;
+    let mut words = words.into_iter().rev().peekable();
+
+    while let Some(word) = words.next() {
+        let word = word.trim_matches(|c: char| c == ',' || c == '.' || c == ';');
+
+        let value = |number: &str| match number {
+            "a" | "an" | "one" => Some(1),
+            number => number.parse::<u64>().ok(),
+        };
+
+        // Compact units like "1m" or "23s".
+        if let Some(split) = word
+            .find(|c: char| !c.is_ascii_digit())
+            .filter(|split| *split > 0)
+        {
+            let (number, unit) = word.split_at(split);
+            seconds += value(number)? * unit_seconds(unit)?;
+        } else if let Some(number) = value(word) {
+            let unit = words.next()?;
+            seconds += number * unit_seconds(unit.trim_end_matches(['.', ',', ';']))?;
+        } else {
+            return None;
+        }
+    }
+
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+fn unit_seconds(unit: &str) -> Option<u64> {
+    match unit {
+        "s" | "second" | "seconds" => Some(1),
+        "m" | "minute" | "minutes" => Some(60),
+        "h" | "hour" | "hours" => Some(3600),
+        _ => None,
+    }
+}
+
+fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{seconds}s"),
+        (minutes, 0) => format!("{minutes}m"),
+        (minutes, seconds) => format!("{minutes}m {seconds}s"),
+    }
+}
+
+/// The current time in seconds since the epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Reads every recorded submission. A missing file has none.
+pub fn read_history(path: &Path) -> io::Result<Vec<Submission>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            json::parse(line)
+                .and_then(|value| Submission::from_json(&value))
+                .map_err(|error| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}:{}: {error}", path.display(), idx + 1),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Appends a submission to the history, creating it if needed.
+pub fn append_history(path: &Path, submission: &Submission) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(file, "{}", submission.to_json())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(answer: &str, time: u64, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2024,
+            day: 3,
+            part: 2,
+            answer: answer.to_string(),
+            time,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(
+                "<main><article><p>That's the right answer! You are <em>one gold star</em> closer \
+                 to finding the Chief Historian.</p></article></main>"
+            ),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>That's not the right answer; your answer is too high.  If you're \
+                 stuck, make sure you're using the full input data. Please wait one minute \
+                 before trying again. [<a href=\"/2024/day/3\">Return to Day 3</a>]</p></article>"
+            ),
+            Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>That's not the right answer; your answer is too low. Please wait \
+                 5 minutes before trying again.</p></article>"
+            ),
+            Outcome::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300)),
+            }
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>You gave an answer too recently; you have to wait after \
+                 submitting an answer before trying again.  You have 1m 23s left to wait.\
+                 </p></article>"
+            ),
+            Outcome::TooSoon {
+                wait: Duration::from_secs(83),
+            }
+        );
+        assert_eq!(
+            parse_response(
+                "<article><p>You don't seem to be solving the right level.  Did you already \
+                 complete it?</p></article>"
+            ),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_response("<p>Server <b>error</b></p>"),
+            Outcome::Unknown("Server error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_submission() {
+        let history = [
+            submission(
+                "500",
+                1000,
+                Outcome::Wrong {
+                    hint: Some(Hint::TooHigh),
+                    wait: None,
+                },
+            ),
+            submission(
+                "100",
+                1100,
+                Outcome::Wrong {
+                    hint: Some(Hint::TooLow),
+                    wait: Some(Duration::from_secs(300)),
+                },
+            ),
+            submission(
+                "abc",
+                1200,
+                Outcome::Wrong {
+                    hint: None,
+                    wait: None,
+                },
+            ),
+        ];
+
+        assert_eq!(check_submission(&history, 2024, 3, 2, "250", 2000), Ok(()));
+        assert_eq!(
+            check_submission(&history, 2024, 3, 2, "abc", 2000),
+            Err(Refusal::KnownWrong)
+        );
+        assert_eq!(
+            check_submission(&history, 2024, 3, 2, "600", 2000),
+            Err(Refusal::TooHigh {
+                than: "500".to_string()
+            })
+        );
+        assert_eq!(
+            check_submission(&history, 2024, 3, 2, "90", 2000),
+            Err(Refusal::TooLow {
+                than: "100".to_string()
+            })
+        );
+        assert_eq!(
+            check_submission(&history, 2024, 4, 1, "1", 1300),
+            Err(Refusal::Cooldown {
+                wait: Duration::from_secs(100)
+            })
+        );
+
+        let solved = [submission("250", 1000, Outcome::Correct)];
+
+        assert_eq!(
+            check_submission(&solved, 2024, 3, 2, "251", 2000),
+            Err(Refusal::AlreadySolved {
+                answer: "250".to_string()
+            })
+        );
+        assert_eq!(check_submission(&solved, 2024, 3, 1, "251", 2000), Ok(()));
+        assert_eq!(check_submission(&solved, 2023, 3, 2, "251", 2000), Ok(()));
+    }
+
+    #[test]
+    fn test_history_json() {
+        let submissions = [
+            submission("7,5,4", 1, Outcome::Correct),
+            submission(
+                "12",
+                2,
+                Outcome::Wrong {
+                    hint: Some(Hint::TooLow),
+                    wait: Some(Duration::from_secs(60)),
+                },
+            ),
+            submission(
+                "12",
+                3,
+                Outcome::TooSoon {
+                    wait: Duration::from_secs(30),
+                },
+            ),
+            submission("12", 4, Outcome::Unknown("Server error".to_string())),
+        ];
+
+        for submission in submissions {
+            let line = submission.to_json().to_string();
+
+            assert_eq!(
+                json::parse(&line).and_then(|value| Submission::from_json(&value)),
+                Ok(submission)
+            );
+        }
+
+        let no_year = r#"{"day":3,"part":2,"answer":"7,5,4","time":1,"outcome":"correct"}"#;
+
+        assert!(json::parse(no_year)
+            .and_then(|value| Submission::from_json(&value))
+            .is_err());
+    }
+}
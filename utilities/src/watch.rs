@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::json;
+
+/// The modification times of the watched files, with `None` for files that are gone.
+pub type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The answers of one run, by input and part, with the error message of parts that failed.
+pub type RunAnswers = BTreeMap<(String, usize), Result<String, String>>;
+
+/// Looks up the modification times of `paths`, and of the files directly inside the ones that
+/// are directories, so adding or removing an example counts as a change.
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    for path in paths {
+        snapshot.insert(path.clone(), modified(path));
+
+        let Ok(entries) = fs::read_dir(path) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            snapshot.insert(path.clone(), modified(&path));
+        }
+    }
+
+    snapshot
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Reads the answers from the JSON lines the runner prints with `--format json`, filing them
+/// under `input`.
+pub fn parse_answers(input: &str, output: &str) -> Result<RunAnswers, String> {
+    let mut answers = RunAnswers::new();
+
+    for line in output.lines().filter(|line| !line.trim().is_empty()) {
+        let record = json::parse(line)?;
+        let field = |key| record.get(key).and_then(json::Value::as_str);
+
+        let part = record
+            .get("part")
+            .and_then(json::Value::as_usize)
+            .ok_or(format!("No part in '{line}'"))?;
+
+        let answer = match (field("answer"), field("error")) {
+            (Some(answer), _) => Ok(answer.to_string()),
+            (None, error) => Err(error.unwrap_or("unknown error").to_string()),
+        };
+
+        answers.insert((input.to_string(), part), answer);
+    }
+
+    Ok(answers)
+}
+
+/// A line for each answer that's new or differs from the previous run, followed by how many
+/// stayed the same.
+pub fn diff_answers(previous: &RunAnswers, current: &RunAnswers) -> Vec<String> {
+    let mut lines = vec![];
+    let mut unchanged = 0;
+
+    for ((input, part), answer) in current {
+        let answer = show(answer);
+
+        match previous.get(&(input.clone(), *part)).map(show) {
+            Some(before) if before == answer => unchanged += 1,
+            Some(before) => lines.push(format!("{input} part {part}: {before} -> {answer}")),
+            None => lines.push(format!("{input} part {part}: {answer}")),
+        }
+    }
+
+    for (input, part) in previous.keys() {
+        if !current.contains_key(&(input.clone(), *part)) {
+            lines.push(format!("{input} part {part}: no longer run"));
+        }
+    }
+
+    if unchanged > 0 {
+        lines.push(format!("{unchanged} unchanged"));
+    }
+
+    lines
+}
+
+fn show(answer: &Result<String, String>) -> String {
+    match answer {
+        Ok(answer) => answer.clone(),
+        Err(error) => format!("error: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = r#"{"year":2024,"day":7,"part":1,"status":"ok","answer":"3749","error":null}
+{"year":2024,"day":7,"part":2,"status":"error","answer":null,"error":"no solution"}
+"#;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("example.txt", OUTPUT).unwrap();
+
+        assert_eq!(
+            answers.into_iter().collect::<Vec<_>>(),
+            [
+                (("example.txt".to_string(), 1), Ok("3749".to_string())),
+                (
+                    ("example.txt".to_string(), 2),
+                    Err("no solution".to_string())
+                ),
+            ]
+        );
+        assert!(parse_answers("example.txt", "error: could not compile").is_err());
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = parse_answers("example.txt", OUTPUT).unwrap();
+        let mut current = previous.clone();
+
+        assert_eq!(diff_answers(&previous, &current), ["2 unchanged"]);
+
+        current.insert(("example.txt".to_string(), 2), Ok("11387".to_string()));
+        current.insert(("input".to_string(), 1), Ok("42".to_string()));
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            [
+                "example.txt part 2: error: no solution -> 11387",
+                "input part 1: 42",
+                "1 unchanged",
+            ]
+        );
+        assert_eq!(
+            diff_answers(&current, &previous),
+            [
+                "example.txt part 2: 11387 -> error: no solution",
+                "input part 1: no longer run",
+                "1 unchanged",
+            ]
+        );
+    }
+}
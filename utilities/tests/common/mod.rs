@@ -0,0 +1,122 @@
+// Each test binary uses a different part of these helpers.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A request the stub server received.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A local HTTP server standing in for the puzzle site. Every request is recorded and answered
+/// with the status and body the handler returns.
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&Request) -> (u16, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let Some(request) = read_request(&mut BufReader::new(&stream)) else {
+                    continue;
+                };
+
+                let (status, body) = handler(&request);
+                recorded.lock().unwrap().push(request);
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Option<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+
+    let mut words = line.split_ascii_whitespace();
+    let method = words.next()?.to_string();
+    let path = words.next()?.to_string();
+
+    let mut headers = vec![];
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+
+        let Some((name, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    let length = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+/// An empty directory that no other test uses.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("aoc_{name}_{}_{nanos}", std::process::id()));
+
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
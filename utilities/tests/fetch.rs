@@ -0,0 +1,84 @@
+mod common;
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+use common::{temp_dir, StubServer};
+use utilities::client::{Client, ClientError};
+use utilities::fetch::{cached_input_path, fetch_input, FetchError, Fetched};
+
+fn stub_site() -> StubServer {
+    StubServer::start(|request| match request.path.as_str() {
+        _ if request.header("Cookie") != Some("session=secret") => {
+            (400, "Puzzle inputs differ by user.".to_string())
+        }
+        "/2024/day/1/input" => (200, "3   4\n4   3\n".to_string()),
+        "/2024/day/2/input" => (200, "7 6 4 2 1\n".to_string()),
+        _ => (404, "Not Found".to_string()),
+    })
+}
+
+fn client(server: &StubServer, session: &str) -> Client {
+    Client::new(&server.url, session).with_min_interval(Duration::ZERO)
+}
+
+#[test]
+fn test_fetch_and_cache() {
+    let server = stub_site();
+    let root = temp_dir("fetch_cache");
+    let mut client = client(&server, "secret");
+
+    let path = cached_input_path(&root, 2024, 1);
+
+    assert_eq!(
+        fetch_input(&mut client, &root, 2024, 1).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+    assert_eq!(
+        fetch_input(&mut client, &root, 2024, 1).unwrap(),
+        Fetched::Cached(path)
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "GET");
+    assert!(request
+        .header("User-Agent")
+        .unwrap()
+        .contains("advent_of_code/"));
+}
+
+#[test]
+fn test_fetch_errors() {
+    let server = stub_site();
+    let root = temp_dir("fetch_errors");
+
+    let error = fetch_input(&mut client(&server, "expired"), &root, 2024, 1).unwrap_err();
+    assert!(matches!(
+        error,
+        FetchError::Client(ClientError::Status { status: 400, .. })
+    ));
+
+    let error = fetch_input(&mut client(&server, "secret"), &root, 2024, 25).unwrap_err();
+    assert!(error.to_string().contains("not be unlocked yet"));
+
+    assert!(!cached_input_path(&root, 2024, 1).exists());
+    assert!(!cached_input_path(&root, 2024, 25).exists());
+}
+
+#[test]
+fn test_rate_limit() {
+    let server = stub_site();
+    let root = temp_dir("fetch_rate_limit");
+    let mut client =
+        Client::new(&server.url, "secret").with_min_interval(Duration::from_millis(300));
+
+    let start = Instant::now();
+    fetch_input(&mut client, &root, 2024, 1).unwrap();
+    fetch_input(&mut client, &root, 2024, 2).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 2);
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use std::fs;
+
+use common::temp_dir;
+use utilities::named_inputs::{named_inputs, NamedInput};
+
+#[test]
+fn test_named_inputs() {
+    let root = temp_dir("named_inputs");
+    let dir = root.join("2024").join("day7").join("inputs");
+    fs::create_dir_all(&dir).unwrap();
+
+    for file in ["bob.txt", "alice.txt", "alice.answers", "notes.md"] {
+        fs::write(dir.join(file), "").unwrap();
+    }
+
+    let inputs = named_inputs(&root, 2024, 7).unwrap();
+
+    assert_eq!(
+        inputs,
+        [
+            NamedInput {
+                name: "alice".to_string(),
+                path: dir.join("alice.txt"),
+            },
+            NamedInput {
+                name: "bob".to_string(),
+                path: dir.join("bob.txt"),
+            },
+        ]
+    );
+    assert_eq!(inputs[0].answers_path(), dir.join("alice.answers"));
+    assert!(named_inputs(&root, 2024, 8).unwrap().is_empty());
+    assert!(named_inputs(&root, 2023, 7).unwrap().is_empty());
+}
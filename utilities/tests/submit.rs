@@ -0,0 +1,85 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{temp_dir, StubServer};
+use utilities::client::Client;
+use utilities::submit::{self, Hint, Outcome, Refusal, Submission};
+
+const WRONG: &str = "<html><body><main><article><p>That's not the right answer; your answer is \
+                     too low.  If you're stuck, make sure you're using the full input data. \
+                     Please wait one minute before trying again.</p></article></main></body></html>";
+
+const RIGHT: &str = "<html><body><main><article><p>That's the right answer!  You are <span \
+                     class=\"day-success\">one gold star</span> closer to finding the Chief \
+                     Historian.</p></article></main></body></html>";
+
+fn stub_site() -> StubServer {
+    StubServer::start(|request| match request.path.as_str() {
+        "/2024/day/3/answer" if request.body.contains("answer=161") => (200, RIGHT.to_string()),
+        "/2024/day/3/answer" => (200, WRONG.to_string()),
+        _ => (404, "Not Found".to_string()),
+    })
+}
+
+#[test]
+fn test_submit_and_record() {
+    let server = stub_site();
+    let history_path = temp_dir("submit_history").join("submissions.jsonl");
+    let mut client = Client::new(&server.url, "secret").with_min_interval(Duration::ZERO);
+
+    let outcome = submit::parse_response(&client.submit(2024, 3, 1, "150").unwrap());
+    assert_eq!(
+        outcome,
+        Outcome::Wrong {
+            hint: Some(Hint::TooLow),
+            wait: Some(Duration::from_secs(60)),
+        }
+    );
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.body, "level=1&answer=150");
+    assert_eq!(request.header("Cookie"), Some("session=secret"));
+    assert_eq!(
+        request.header("Content-Type"),
+        Some("application/x-www-form-urlencoded")
+    );
+
+    let wrong = Submission {
+        year: 2024,
+        day: 3,
+        part: 1,
+        answer: "150".to_string(),
+        time: 1000,
+        outcome,
+    };
+    submit::append_history(&history_path, &wrong).unwrap();
+
+    let history = submit::read_history(&history_path).unwrap();
+    assert_eq!(history, [wrong]);
+    assert_eq!(
+        submit::check_submission(&history, 2024, 3, 1, "140", 2000),
+        Err(Refusal::TooLow {
+            than: "150".to_string()
+        })
+    );
+    assert_eq!(
+        submit::check_submission(&history, 2024, 3, 1, "161", 1030),
+        Err(Refusal::Cooldown {
+            wait: Duration::from_secs(30)
+        })
+    );
+    assert_eq!(
+        submit::check_submission(&history, 2024, 3, 1, "161", 2000),
+        Ok(())
+    );
+    assert_eq!(
+        submit::check_submission(&history, 2023, 3, 1, "140", 2000),
+        Ok(())
+    );
+
+    let outcome = submit::parse_response(&client.submit(2024, 3, 1, "161").unwrap());
+    assert_eq!(outcome, Outcome::Correct);
+    assert_eq!(server.requests().len(), 2);
+}